@@ -1,7 +1,7 @@
 //! C bindings for librespot connect (Spirc).
 
 use std::future::Future;
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_void};
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::ptr;
@@ -9,10 +9,16 @@ use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use librespot::connect::{ConnectConfig, LoadRequest, LoadRequestOptions, Spirc};
+use librespot::core::cache::Cache;
+use librespot::core::config::SessionConfig;
+use librespot::core::session::Session;
 use librespot::core::{Error as LibrespotError, SpotifyUri};
+use librespot::discovery::Credentials;
 use librespot::metadata::audio::{AudioItem, UniqueFields};
+use librespot::playback::mixer::Mixer;
 use librespot::playback::player::{Player, PlayerEvent};
 use tokio::task::JoinHandle;
+use tokio::time::Duration;
 
 use crate::discovery::{credentials_from_handle, cspot_device_type_t};
 use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
@@ -21,6 +27,12 @@ use crate::playback::{cspot_mixer_t, cspot_player_t, mixer_from_handle, player_f
 use crate::runtime::runtime;
 use crate::session::{cspot_session_t, session_from_handle};
 
+const DEFAULT_RECONNECT_BACKOFF_CAP_MS: u64 = 30_000;
+const INITIAL_RECONNECT_BACKOFF_MS: u64 = 1_000;
+/// Stops auto-skip once this many tracks in a row have been unplayable, so a
+/// context that's entirely region-locked/filtered doesn't spin forever.
+const MAX_CONSECUTIVE_SKIPS: u32 = 20;
+
 /// Opaque connect configuration handle for C callers.
 #[allow(non_camel_case_types)]
 pub struct cspot_connect_config_t;
@@ -48,6 +60,37 @@ pub enum cspot_playback_state_t {
     CSPOT_PLAYBACK_STATE_INVALID = -1,
 }
 
+/// Policy applied when the current track is unplayable (region-locked,
+/// filtered by `cspot_session` explicit-content settings, etc.).
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum cspot_unplayable_policy_t {
+    CSPOT_UNPLAYABLE_STOP = 0,
+    CSPOT_UNPLAYABLE_SKIP = 1,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum UnplayablePolicy {
+    Stop,
+    Skip,
+}
+
+impl Default for UnplayablePolicy {
+    fn default() -> Self {
+        Self::Stop
+    }
+}
+
+impl From<cspot_unplayable_policy_t> for UnplayablePolicy {
+    fn from(value: cspot_unplayable_policy_t) -> Self {
+        match value {
+            cspot_unplayable_policy_t::CSPOT_UNPLAYABLE_STOP => Self::Stop,
+            cspot_unplayable_policy_t::CSPOT_UNPLAYABLE_SKIP => Self::Skip,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum PlaybackState {
     Stopped,
@@ -94,6 +137,11 @@ struct SpircStatusSnapshot {
     shuffle_enabled: bool,
     repeat_context_enabled: bool,
     repeat_track_enabled: bool,
+    autoplay_active: bool,
+    buffered_position_ms: u32,
+    fully_buffered: bool,
+    next_track_preloaded: bool,
+    reconnecting: bool,
     track: TrackMetadata,
 }
 
@@ -107,6 +155,16 @@ struct SpircRuntimeStatus {
     shuffle_enabled: bool,
     repeat_context_enabled: bool,
     repeat_track_enabled: bool,
+    autoplay_active: bool,
+    buffered_position_ms: u32,
+    fully_buffered: bool,
+    next_track_preloaded: bool,
+    reconnecting: bool,
+    /// Track URIs from the most recently loaded explicit track list, used to
+    /// detect when playback has moved on to an autoplay/station continuation.
+    /// `None` for context loads (album/playlist), where we have no way to
+    /// enumerate membership ourselves.
+    loaded_track_uris: Option<Vec<String>>,
     track: TrackMetadata,
 }
 
@@ -121,10 +179,22 @@ impl SpircRuntimeStatus {
             shuffle_enabled: self.shuffle_enabled,
             repeat_context_enabled: self.repeat_context_enabled,
             repeat_track_enabled: self.repeat_track_enabled,
+            autoplay_active: self.autoplay_active,
+            buffered_position_ms: self.buffered_position_ms,
+            fully_buffered: self.fully_buffered,
+            next_track_preloaded: self.next_track_preloaded,
+            reconnecting: self.reconnecting,
             track: self.track.clone(),
         }
     }
 
+    /// Clears the explicit-load tracking state, as happens on a fresh
+    /// `load`/`transfer` command.
+    fn reset_autoplay_tracking(&mut self, loaded_track_uris: Option<Vec<String>>) {
+        self.loaded_track_uris = loaded_track_uris;
+        self.autoplay_active = false;
+    }
+
     fn current_position_ms(&self) -> u32 {
         let mut position_ms = self.position_anchor_ms;
         if self.playback_state == PlaybackState::Playing {
@@ -216,18 +286,55 @@ impl SpircRuntimeStatus {
     }
 }
 
+/// Automatic reconnection policy for a `cspot_spirc_t` created from this config.
+#[derive(Clone, Debug)]
+struct ReconnectPolicy {
+    enabled: bool,
+    backoff_cap_ms: u64,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backoff_cap_ms: DEFAULT_RECONNECT_BACKOFF_CAP_MS,
+        }
+    }
+}
+
 struct ConnectConfigHandle {
     config: ConnectConfig,
+    reconnect: ReconnectPolicy,
 }
 
 struct LoadRequestOptionsHandle {
     options: LoadRequestOptions,
+    /// Context URI to load via `cspot_spirc_load_context`. Unused by
+    /// `cspot_spirc_load`/`cspot_spirc_load_tracks`, which take their target
+    /// as a separate argument instead.
+    context_uri: Option<String>,
+}
+
+/// Mutable state shared between the C-facing `SpircHandle` and, when
+/// auto-reconnect is enabled, the background supervisor task. Kept behind an
+/// `Arc` so the supervisor can keep rebuilding it after a disconnect while the
+/// opaque `cspot_spirc_t` pointer identity held by the C caller never changes.
+struct SpircCore {
+    spirc: Mutex<Spirc>,
+    status: Arc<Mutex<SpircRuntimeStatus>>,
+    status_task: Mutex<JoinHandle<()>>,
+    event_callback: Arc<Mutex<Option<EventCallback>>>,
+    unplayable_policy: Mutex<UnplayablePolicy>,
+    consecutive_skips: Mutex<u32>,
+    /// Host-level autoplay preference, applied to loads that don't already
+    /// request it via `cspot_load_request_options_set_autoplay`.
+    autoplay_enabled: Mutex<bool>,
 }
 
 struct SpircHandle {
-    spirc: Spirc,
-    status: Arc<Mutex<SpircRuntimeStatus>>,
-    status_task: JoinHandle<()>,
+    core: Arc<SpircCore>,
+    /// Present only when auto-reconnect is enabled; owns the reconnect loop.
+    supervisor_task: Option<JoinHandle<()>>,
 }
 
 struct SpircTaskHandle {
@@ -249,11 +356,117 @@ fn spotify_item_id(uri: &SpotifyUri) -> Option<String> {
     }
 }
 
-fn apply_player_event(status: &mut SpircRuntimeStatus, event: PlayerEvent) {
+/// Kinds of events delivered through `cspot_spirc_set_event_callback`.
+///
+/// Covers track changes, play/pause/position/volume/shuffle/repeat state
+/// changes, and Spotify Connect session connect/disconnect, so GUI consumers
+/// can react immediately instead of polling the `cspot_spirc_current_*` and
+/// `cspot_spirc_is_*` getters.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum cspot_player_event_kind_t {
+    CSPOT_PLAYER_EVENT_TRACK_CHANGED = 0,
+    CSPOT_PLAYER_EVENT_PLAYING = 1,
+    CSPOT_PLAYER_EVENT_PAUSED = 2,
+    CSPOT_PLAYER_EVENT_SEEKED = 3,
+    CSPOT_PLAYER_EVENT_VOLUME_CHANGED = 4,
+    CSPOT_PLAYER_EVENT_SHUFFLE_CHANGED = 5,
+    CSPOT_PLAYER_EVENT_REPEAT_CHANGED = 6,
+    CSPOT_PLAYER_EVENT_SESSION_CONNECTED = 7,
+    CSPOT_PLAYER_EVENT_SESSION_DISCONNECTED = 8,
+    CSPOT_PLAYER_EVENT_POSITION_CHANGED = 9,
+    CSPOT_PLAYER_EVENT_TRACK_SKIPPED = 10,
+}
+
+/// Structured player event delivered to `cspot_spirc_set_event_callback`.
+///
+/// String pointers are only valid for the duration of the callback and must not
+/// be retained. Fields that don't apply to `kind` hold their last known value.
+#[repr(C)]
+pub struct cspot_player_event_t {
+    pub kind: cspot_player_event_kind_t,
+    pub track_id: *const c_char,
+    pub track_uri: *const c_char,
+    pub position_ms: u32,
+    pub volume: u16,
+    pub shuffle: bool,
+    pub repeat_context: bool,
+    pub repeat_track: bool,
+}
+
+/// Callback invoked for each Spirc/player event.
+///
+/// May be invoked from a dedicated cspot worker thread; it is never re-entered
+/// concurrently with itself.
+#[allow(non_camel_case_types)]
+pub type cspot_spirc_event_callback_t =
+    Option<extern "C" fn(event: *const cspot_player_event_t, user_data: *mut c_void)>;
+
+struct EventCallback {
+    callback: cspot_spirc_event_callback_t,
+    user_data: usize,
+}
+
+struct PlayerEventNotice {
+    kind: cspot_player_event_kind_t,
+    track_id: Option<String>,
+    track_uri: Option<String>,
+    position_ms: u32,
+    volume: u16,
+    shuffle: bool,
+    repeat_context: bool,
+    repeat_track: bool,
+}
+
+fn apply_player_event(
+    status: &mut SpircRuntimeStatus,
+    event: PlayerEvent,
+) -> Option<PlayerEventNotice> {
+    let notice = |status: &SpircRuntimeStatus, kind: cspot_player_event_kind_t| PlayerEventNotice {
+        kind,
+        track_id: status.track.spotify_id.clone(),
+        track_uri: status.track.uri.clone(),
+        position_ms: status.current_position_ms(),
+        volume: status.volume,
+        shuffle: status.shuffle_enabled,
+        repeat_context: status.repeat_context_enabled,
+        repeat_track: status.repeat_track_enabled,
+    };
+
     match event {
-        PlayerEvent::SessionConnected { .. } => status.connected = true,
-        PlayerEvent::SessionDisconnected { .. } => status.connected = false,
-        PlayerEvent::TrackChanged { audio_item } => status.set_track_metadata(&audio_item),
+        PlayerEvent::SessionConnected { .. } => {
+            status.connected = true;
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_SESSION_CONNECTED,
+            ))
+        }
+        PlayerEvent::SessionDisconnected { .. } => {
+            status.connected = false;
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_SESSION_DISCONNECTED,
+            ))
+        }
+        PlayerEvent::TrackChanged { audio_item } => {
+            status.set_track_metadata(&audio_item);
+            status.buffered_position_ms = 0;
+            status.fully_buffered = false;
+            status.next_track_preloaded = false;
+            if let Some(loaded_track_uris) = &status.loaded_track_uris {
+                let is_known = status
+                    .track
+                    .uri
+                    .as_deref()
+                    .is_some_and(|uri| loaded_track_uris.iter().any(|known| known == uri));
+                status.autoplay_active = !is_known;
+            }
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_TRACK_CHANGED,
+            ))
+        }
         PlayerEvent::Loading {
             track_id,
             position_ms,
@@ -262,13 +475,22 @@ fn apply_player_event(status: &mut SpircRuntimeStatus, event: PlayerEvent) {
             status.set_playback_state(PlaybackState::Loading);
             status.set_track_identity(&track_id);
             status.set_position(position_ms, false);
+            None
         }
         PlayerEvent::Playing {
             track_id,
             position_ms,
             ..
+        } => {
+            status.set_playback_state(PlaybackState::Playing);
+            status.set_track_identity(&track_id);
+            status.set_position(position_ms, true);
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_PLAYING,
+            ))
         }
-        | PlayerEvent::PositionChanged {
+        PlayerEvent::PositionChanged {
             track_id,
             position_ms,
             ..
@@ -281,6 +503,10 @@ fn apply_player_event(status: &mut SpircRuntimeStatus, event: PlayerEvent) {
             status.set_playback_state(PlaybackState::Playing);
             status.set_track_identity(&track_id);
             status.set_position(position_ms, true);
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_POSITION_CHANGED,
+            ))
         }
         PlayerEvent::Paused {
             track_id,
@@ -290,6 +516,10 @@ fn apply_player_event(status: &mut SpircRuntimeStatus, event: PlayerEvent) {
             status.set_playback_state(PlaybackState::Paused);
             status.set_track_identity(&track_id);
             status.set_position(position_ms, false);
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_PAUSED,
+            ))
         }
         PlayerEvent::Seeked {
             track_id,
@@ -299,31 +529,231 @@ fn apply_player_event(status: &mut SpircRuntimeStatus, event: PlayerEvent) {
             status.set_track_identity(&track_id);
             let is_playing = status.playback_state == PlaybackState::Playing;
             status.set_position(position_ms, is_playing);
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_SEEKED,
+            ))
         }
         PlayerEvent::Stopped { track_id, .. } => {
             status.set_playback_state(PlaybackState::Stopped);
             status.set_track_identity(&track_id);
             status.set_position(0, false);
+            None
+        }
+        PlayerEvent::VolumeChanged { volume } => {
+            status.volume = volume;
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_VOLUME_CHANGED,
+            ))
+        }
+        PlayerEvent::ShuffleChanged { shuffle } => {
+            status.shuffle_enabled = shuffle;
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_SHUFFLE_CHANGED,
+            ))
+        }
+        PlayerEvent::Preloading { .. } => {
+            // librespot only begins preloading the next track once the current
+            // track's stream is fully buffered to end-of-file, so this also
+            // doubles as our "fully buffered" signal for the playing track.
+            status.fully_buffered = true;
+            status.buffered_position_ms = status.track.duration_ms;
+            status.next_track_preloaded = true;
+            None
         }
-        PlayerEvent::VolumeChanged { volume } => status.volume = volume,
-        PlayerEvent::ShuffleChanged { shuffle } => status.shuffle_enabled = shuffle,
         PlayerEvent::RepeatChanged { context, track } => {
             status.repeat_context_enabled = context;
             status.repeat_track_enabled = track;
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_REPEAT_CHANGED,
+            ))
+        }
+        PlayerEvent::Unavailable { track_id, .. } => {
+            status.set_track_identity(&track_id);
+            Some(notice(
+                status,
+                cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_TRACK_SKIPPED,
+            ))
         }
-        _ => {}
+        _ => None,
     }
 }
 
-fn spawn_status_task(
-    player: &Arc<Player>,
-    status: Arc<Mutex<SpircRuntimeStatus>>,
-) -> JoinHandle<()> {
+fn invoke_event_callback(event_callback: &Mutex<Option<EventCallback>>, notice: PlayerEventNotice) {
+    let (callback, user_data) = {
+        let guard = event_callback.lock().unwrap_or_else(|err| err.into_inner());
+        match guard.as_ref() {
+            Some(state) => (state.callback, state.user_data as *mut c_void),
+            None => return,
+        }
+    };
+    let Some(callback) = callback else {
+        return;
+    };
+
+    let track_id = notice.track_id.as_deref().map(cstring_from_str_lossy);
+    let track_uri = notice.track_uri.as_deref().map(cstring_from_str_lossy);
+    let event = cspot_player_event_t {
+        kind: notice.kind,
+        track_id: track_id.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        track_uri: track_uri.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        position_ms: notice.position_ms,
+        volume: notice.volume,
+        shuffle: notice.shuffle,
+        repeat_context: notice.repeat_context,
+        repeat_track: notice.repeat_track,
+    };
+    if std::panic::catch_unwind(AssertUnwindSafe(|| callback(&event, user_data))).is_err() {
+        eprintln!("cspot: panic in spirc event callback");
+    }
+}
+
+fn spawn_status_task(player: &Arc<Player>, core: Arc<SpircCore>) -> JoinHandle<()> {
     let mut event_channel = player.get_player_event_channel();
     runtime().spawn(async move {
         while let Some(event) = event_channel.recv().await {
-            let mut guard = status.lock().unwrap_or_else(|err| err.into_inner());
-            apply_player_event(&mut guard, event);
+            let is_unavailable = matches!(event, PlayerEvent::Unavailable { .. });
+            let notice = {
+                let mut guard = core.status.lock().unwrap_or_else(|err| err.into_inner());
+                apply_player_event(&mut guard, event)
+            };
+
+            if is_unavailable {
+                handle_unplayable_track(&core);
+            } else if matches!(
+                notice.as_ref().map(|notice| notice.kind),
+                Some(
+                    cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_TRACK_CHANGED
+                        | cspot_player_event_kind_t::CSPOT_PLAYER_EVENT_PLAYING
+                )
+            ) {
+                *core
+                    .consecutive_skips
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner()) = 0;
+            }
+
+            if let Some(notice) = notice {
+                invoke_event_callback(&core.event_callback, notice);
+            }
+        }
+    })
+}
+
+/// Applies the configured unplayable-track policy: in skip mode, advances to
+/// the next track (bounded by `MAX_CONSECUTIVE_SKIPS` so a context that's
+/// entirely unplayable eventually stops instead of looping forever).
+fn handle_unplayable_track(core: &Arc<SpircCore>) {
+    let policy = *core
+        .unplayable_policy
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if policy != UnplayablePolicy::Skip {
+        return;
+    }
+
+    let mut skips = core
+        .consecutive_skips
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if *skips >= MAX_CONSECUTIVE_SKIPS {
+        return;
+    }
+    *skips += 1;
+    drop(skips);
+
+    let guard = core.spirc.lock().unwrap_or_else(|err| err.into_inner());
+    if let Err(err) = guard.next() {
+        eprintln!("cspot: failed to skip unplayable track: {err}");
+    }
+}
+
+/// Drives a Spirc task to completion, then repeatedly re-establishes the
+/// session and rebuilds Spirc from the stored credentials, with exponential
+/// backoff between attempts. Runs for the lifetime of the `cspot_spirc_t`
+/// handle; `cspot_spirc_free` aborts it.
+///
+/// The same `player`/`mixer` are reused across reconnects rather than being
+/// rebuilt against the new session, since the FFI surface here has no way to
+/// reconstruct the audio backend/PCM callback a caller originally configured
+/// them with. `session_config`/`cache` are the exact values the original
+/// session was built with (captured in `cspot_spirc_create`), so a reconnect
+/// doesn't silently drop the caller's on-disk cache or non-default session
+/// settings (proxy, AP overrides, client id).
+#[allow(clippy::too_many_arguments)]
+fn spawn_reconnect_supervisor(
+    core: Arc<SpircCore>,
+    initial_task: Pin<Box<dyn Future<Output = ()> + Send>>,
+    session_config: SessionConfig,
+    cache: Option<Cache>,
+    credentials: Credentials,
+    config: ConnectConfig,
+    player: Arc<Player>,
+    mixer: Arc<dyn Mixer>,
+    backoff_cap_ms: u64,
+) -> JoinHandle<()> {
+    runtime().spawn(async move {
+        // `None` after a failed reconnect attempt, so the loop backs off and
+        // retries instead of waiting on a task that has already finished.
+        let mut task = Some(initial_task);
+        let mut backoff_ms = INITIAL_RECONNECT_BACKOFF_MS;
+        loop {
+            if let Some(task) = task.take() {
+                task.await;
+            }
+
+            {
+                let mut status = core.status.lock().unwrap_or_else(|err| err.into_inner());
+                status.connected = false;
+                status.reconnecting = true;
+            }
+
+            tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+
+            let reconnect_credentials = credentials.clone();
+            let reconnect_result: Result<_, LibrespotError> = async {
+                let session = Session::new(session_config.clone(), cache.clone());
+                session.connect(reconnect_credentials.clone(), false).await?;
+                Spirc::new(
+                    config.clone(),
+                    session,
+                    reconnect_credentials,
+                    Arc::clone(&player),
+                    Arc::clone(&mixer),
+                )
+                .await
+            }
+            .await;
+
+            match reconnect_result {
+                Ok((new_spirc, new_task)) => {
+                    *core.spirc.lock().unwrap_or_else(|err| err.into_inner()) = new_spirc;
+                    let new_status_task = spawn_status_task(&player, Arc::clone(&core));
+                    core.status_task
+                        .lock()
+                        .unwrap_or_else(|err| err.into_inner())
+                        .abort();
+                    *core
+                        .status_task
+                        .lock()
+                        .unwrap_or_else(|err| err.into_inner()) = new_status_task;
+
+                    {
+                        let mut status = core.status.lock().unwrap_or_else(|err| err.into_inner());
+                        status.connected = true;
+                        status.reconnecting = false;
+                    }
+
+                    backoff_ms = INITIAL_RECONNECT_BACKOFF_MS;
+                    task = Some(Box::pin(new_task));
+                }
+                Err(_) => {
+                    backoff_ms = (backoff_ms * 2).min(backoff_cap_ms);
+                }
+            }
         }
     })
 }
@@ -340,7 +770,8 @@ fn run_spirc_command(
     }
     // Safety: spirc must be a valid handle allocated by cspot.
     let handle = unsafe { &*(spirc as *const SpircHandle) };
-    match command(&handle.spirc) {
+    let guard = handle.core.spirc.lock().unwrap_or_else(|err| err.into_inner());
+    match command(&guard) {
         Ok(()) => true,
         Err(err) => {
             write_error(out_error, err.to_string());
@@ -349,13 +780,28 @@ fn run_spirc_command(
     }
 }
 
+/// Applies the host-level autoplay preference set via
+/// `cspot_spirc_set_autoplay` to `options`, unless the caller already
+/// requested autoplay explicitly on the options handle.
+fn apply_autoplay_preference(handle: &SpircHandle, options: &mut LoadRequestOptions) {
+    if options.autoplay {
+        return;
+    }
+    let enabled = *handle
+        .core
+        .autoplay_enabled
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    options.autoplay = enabled;
+}
+
 fn snapshot_from_spirc(spirc: *const cspot_spirc_t) -> Option<SpircStatusSnapshot> {
     if spirc.is_null() {
         return None;
     }
     // Safety: spirc must be a valid handle allocated by cspot.
     let handle = unsafe { &*(spirc as *const SpircHandle) };
-    let guard = handle.status.lock().unwrap_or_else(|err| err.into_inner());
+    let guard = handle.core.status.lock().unwrap_or_else(|err| err.into_inner());
     Some(guard.snapshot())
 }
 
@@ -373,6 +819,7 @@ fn string_to_owned_ptr(value: Option<String>) -> *mut c_char {
 pub extern "C" fn cspot_connect_config_create_default() -> *mut cspot_connect_config_t {
     let handle = ConnectConfigHandle {
         config: ConnectConfig::default(),
+        reconnect: ReconnectPolicy::default(),
     };
     Box::into_raw(Box::new(handle)) as *mut cspot_connect_config_t
 }
@@ -417,6 +864,51 @@ pub extern "C" fn cspot_connect_config_set_device_type(
     true
 }
 
+/// Enables automatic session reconnection for Spirc handles created from this
+/// configuration.
+///
+/// When enabled, `cspot_spirc_create` hands ownership of the Spirc task to an
+/// internal supervisor instead of returning it through `out_task`; callers
+/// must not call `cspot_spirc_task_run` in that case.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_config_set_auto_reconnect(
+    config: *mut cspot_connect_config_t,
+    auto_reconnect: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if config.is_null() {
+        write_error(out_error, "config handle was null");
+        return false;
+    }
+    // Safety: config must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(config as *mut ConnectConfigHandle) };
+    handle.reconnect.enabled = auto_reconnect;
+    true
+}
+
+/// Sets the maximum reconnect backoff delay in milliseconds.
+///
+/// The supervisor starts at a 1 second delay and doubles it after each failed
+/// reconnect attempt, capped at this value; it resets to 1 second after a
+/// successful reconnect. Has no effect unless auto-reconnect is enabled.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_config_set_reconnect_backoff_cap_ms(
+    config: *mut cspot_connect_config_t,
+    backoff_cap_ms: u64,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if config.is_null() {
+        write_error(out_error, "config handle was null");
+        return false;
+    }
+    // Safety: config must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(config as *mut ConnectConfigHandle) };
+    handle.reconnect.backoff_cap_ms = backoff_cap_ms.max(INITIAL_RECONNECT_BACKOFF_MS);
+    true
+}
+
 /// Frees a connect configuration handle.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_connect_config_free(config: *mut cspot_connect_config_t) {
@@ -436,10 +928,38 @@ pub extern "C" fn cspot_connect_config_free(config: *mut cspot_connect_config_t)
 pub extern "C" fn cspot_load_request_options_create_default() -> *mut cspot_load_request_options_t {
     let handle = LoadRequestOptionsHandle {
         options: LoadRequestOptions::default(),
+        context_uri: None,
     };
     Box::into_raw(Box::new(handle)) as *mut cspot_load_request_options_t
 }
 
+/// Sets the context URI (album/playlist/station) to load via
+/// `cspot_spirc_load_context`.
+///
+/// Combine with `cspot_load_request_options_set_playing_track_index` to
+/// resume mid-context, `_set_shuffle`/`_set_repeat` for playback mode, and
+/// `_set_start_playing(false)` to load into state without starting playback.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_load_request_options_set_context_uri(
+    options: *mut cspot_load_request_options_t,
+    context_uri: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    let context_uri = match read_cstr(context_uri, "context_uri", out_error) {
+        Some(value) => value,
+        None => return false,
+    };
+    // Safety: options must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(options as *mut LoadRequestOptionsHandle) };
+    handle.context_uri = Some(context_uri);
+    true
+}
+
 /// Sets whether the load request should start playing immediately.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_load_request_options_set_start_playing(
@@ -476,6 +996,97 @@ pub extern "C" fn cspot_load_request_options_set_seek_to(
     true
 }
 
+/// Sets whether playback should start in shuffle mode.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_load_request_options_set_shuffle(
+    options: *mut cspot_load_request_options_t,
+    shuffle: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    // Safety: options must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(options as *mut LoadRequestOptionsHandle) };
+    handle.options.shuffle = shuffle;
+    true
+}
+
+/// Sets whether playback should repeat the loaded context.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_load_request_options_set_repeat(
+    options: *mut cspot_load_request_options_t,
+    repeat: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    // Safety: options must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(options as *mut LoadRequestOptionsHandle) };
+    handle.options.repeat = repeat;
+    true
+}
+
+/// Sets whether playback should repeat the current track.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_load_request_options_set_repeat_track(
+    options: *mut cspot_load_request_options_t,
+    repeat_track: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    // Safety: options must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(options as *mut LoadRequestOptionsHandle) };
+    handle.options.repeat_track = repeat_track;
+    true
+}
+
+/// Sets whether autoplay/station continuation should take over once the
+/// loaded context or track list runs out of tracks, instead of stopping.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_load_request_options_set_autoplay(
+    options: *mut cspot_load_request_options_t,
+    autoplay: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    // Safety: options must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(options as *mut LoadRequestOptionsHandle) };
+    handle.options.autoplay = autoplay;
+    true
+}
+
+/// Sets the index within the context to start playback at.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_load_request_options_set_playing_track_index(
+    options: *mut cspot_load_request_options_t,
+    playing_track_index: u32,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    // Safety: options must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(options as *mut LoadRequestOptionsHandle) };
+    handle.options.playing_track_index = playing_track_index;
+    true
+}
+
 /// Frees load request options.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_load_request_options_free(options: *mut cspot_load_request_options_t) {
@@ -490,6 +1101,13 @@ pub extern "C" fn cspot_load_request_options_free(options: *mut cspot_load_reque
 
 /// Creates a new Spirc instance and returns the associated task handle.
 ///
+/// This is the Connect endpoint entry point: it builds a `Spirc` from
+/// `config`'s device name/type/initial volume, runs its task on the shared
+/// runtime, and the remote-control surface below
+/// (`cspot_spirc_play`/`_pause`/`_next`/`_prev`/`_seek_to`/`_set_volume`)
+/// forwards commands to it, so the official Spotify apps can discover and
+/// drive this device once credentials are obtained via `cspot_discovery_create`.
+///
 /// The returned spirc handle must be released with `cspot_spirc_free`.
 /// The task handle must be released with `cspot_spirc_task_free`.
 /// The configuration and credentials are cloned; callers may free their handles
@@ -553,6 +1171,13 @@ pub extern "C" fn cspot_spirc_create(
         }
     };
     let config = config_handle.config.clone();
+    let reconnect_policy = config_handle.reconnect.clone();
+    let reconnect_config = config.clone();
+    let reconnect_credentials = credentials.clone();
+    let reconnect_player = Arc::clone(&player);
+    let reconnect_mixer = Arc::clone(&mixer);
+    let reconnect_session_config = session.config().clone();
+    let reconnect_cache = session.cache().cloned();
     let spirc_player = Arc::clone(&player);
 
     let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
@@ -562,20 +1187,49 @@ pub extern "C" fn cspot_spirc_create(
 
     match result {
         Ok(Ok((spirc, task))) => {
-            let status = Arc::new(Mutex::new(SpircRuntimeStatus::default()));
-            let status_task = spawn_status_task(&player, Arc::clone(&status));
-            let spirc_handle = Box::new(SpircHandle {
-                spirc,
-                status,
-                status_task,
+            let core = Arc::new(SpircCore {
+                spirc: Mutex::new(spirc),
+                status: Arc::new(Mutex::new(SpircRuntimeStatus::default())),
+                // Replaced immediately below; spawn_status_task needs `core`
+                // to already exist so the event loop can reach `core.spirc`.
+                status_task: Mutex::new(runtime().spawn(async {})),
+                event_callback: Arc::new(Mutex::new(None)),
+                unplayable_policy: Mutex::new(UnplayablePolicy::default()),
+                consecutive_skips: Mutex::new(0),
+                autoplay_enabled: Mutex::new(false),
             });
-            let task_handle = Box::new(SpircTaskHandle {
-                task: Some(Box::pin(task)),
+            *core
+                .status_task
+                .lock()
+                .unwrap_or_else(|err| err.into_inner()) = spawn_status_task(&player, Arc::clone(&core));
+
+            let supervisor_task = if reconnect_policy.enabled {
+                Some(spawn_reconnect_supervisor(
+                    Arc::clone(&core),
+                    Box::pin(task),
+                    reconnect_session_config,
+                    reconnect_cache,
+                    reconnect_credentials,
+                    reconnect_config,
+                    reconnect_player,
+                    reconnect_mixer,
+                    reconnect_policy.backoff_cap_ms,
+                ))
+            } else {
+                let task_handle = Box::new(SpircTaskHandle {
+                    task: Some(Box::pin(task)),
+                });
+                // Safety: out_task is non-null and points to writable memory.
+                unsafe {
+                    *out_task = Box::into_raw(task_handle) as *mut cspot_spirc_task_t;
+                }
+                None
+            };
+
+            let spirc_handle = Box::new(SpircHandle {
+                core,
+                supervisor_task,
             });
-            // Safety: out_task is non-null and points to writable memory.
-            unsafe {
-                *out_task = Box::into_raw(task_handle) as *mut cspot_spirc_task_t;
-            }
             Box::into_raw(spirc_handle) as *mut cspot_spirc_t
         }
         Ok(Err(err)) => {
@@ -589,7 +1243,100 @@ pub extern "C" fn cspot_spirc_create(
     }
 }
 
-/// Sends a Connect activate command.
+/// Starts a Spotify Connect endpoint: builds a `Spirc` from `config`/`session`/
+/// `credentials`/`player`/`mixer` and runs its task on `runtime()`.
+///
+/// This is the literal Connect entry point name; it forwards directly to
+/// `cspot_spirc_create` and shares its exact semantics, including `out_task`
+/// being left null when `config` has auto-reconnect enabled. The returned
+/// handle must be released with `cspot_connect_stop` (or `cspot_spirc_free`,
+/// which it wraps).
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_start(
+    config: *const cspot_connect_config_t,
+    session: *const cspot_session_t,
+    credentials: *const crate::discovery::cspot_credentials_t,
+    player: *const cspot_player_t,
+    mixer: *const cspot_mixer_t,
+    out_task: *mut *mut cspot_spirc_task_t,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_spirc_t {
+    cspot_spirc_create(config, session, credentials, player, mixer, out_task, out_error)
+}
+
+/// Stops a Connect endpoint started with `cspot_connect_start`, releasing its
+/// handle and tearing down its background tasks.
+///
+/// Equivalent to `cspot_spirc_free`, under the Connect-subsystem name.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_stop(spirc: *mut cspot_spirc_t) {
+    cspot_spirc_free(spirc)
+}
+
+/// Sends a Connect play command. Equivalent to `cspot_spirc_play`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_play(
+    spirc: *const cspot_spirc_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    cspot_spirc_play(spirc, out_error)
+}
+
+/// Sends a Connect pause command. Equivalent to `cspot_spirc_pause`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_pause(
+    spirc: *const cspot_spirc_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    cspot_spirc_pause(spirc, out_error)
+}
+
+/// Sends a Connect next-track command. Equivalent to `cspot_spirc_next`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_next(
+    spirc: *const cspot_spirc_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    cspot_spirc_next(spirc, out_error)
+}
+
+/// Sends a Connect previous-track command. Equivalent to `cspot_spirc_prev`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_prev(
+    spirc: *const cspot_spirc_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    cspot_spirc_prev(spirc, out_error)
+}
+
+/// Seeks within the current track in milliseconds. Equivalent to
+/// `cspot_spirc_seek_to`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_seek(
+    spirc: *const cspot_spirc_t,
+    position_ms: u32,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    cspot_spirc_seek_to(spirc, position_ms, out_error)
+}
+
+/// Sets absolute volume. Equivalent to `cspot_spirc_set_volume`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_connect_set_volume(
+    spirc: *const cspot_spirc_t,
+    volume: u16,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    cspot_spirc_set_volume(spirc, volume, out_error)
+}
+
+/// Sends a Connect activate command, taking over as the active Connect
+/// device.
+///
+/// Call this before `cspot_spirc_load`/`cspot_spirc_load_tracks` when driving
+/// playback purely locally (no phone/desktop app in the picture), so this
+/// device is the one Spotify considers active and the subsequent load isn't
+/// ignored.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_spirc_activate(
     spirc: *const cspot_spirc_t,
@@ -740,6 +1487,12 @@ pub extern "C" fn cspot_spirc_transfer(
     spirc: *const cspot_spirc_t,
     out_error: *mut *mut cspot_error_t,
 ) -> bool {
+    if !spirc.is_null() {
+        // Safety: spirc must be a valid handle allocated by cspot.
+        let handle = unsafe { &*(spirc as *const SpircHandle) };
+        let mut guard = handle.core.status.lock().unwrap_or_else(|err| err.into_inner());
+        guard.reset_autoplay_tracking(None);
+    }
     run_spirc_command(spirc, out_error, |handle| handle.transfer(None))
 }
 
@@ -793,7 +1546,7 @@ pub extern "C" fn cspot_spirc_load_tracks(
         tracks.push(uri);
     }
 
-    let options = if options.is_null() {
+    let mut options = if options.is_null() {
         LoadRequestOptions::default()
     } else {
         // Safety: options must be a valid handle allocated by cspot.
@@ -801,10 +1554,98 @@ pub extern "C" fn cspot_spirc_load_tracks(
         handle.options.clone()
     };
 
+    if !spirc.is_null() {
+        // Safety: spirc must be a valid handle allocated by cspot.
+        let handle = unsafe { &*(spirc as *const SpircHandle) };
+        apply_autoplay_preference(handle, &mut options);
+        let mut guard = handle.core.status.lock().unwrap_or_else(|err| err.into_inner());
+        guard.reset_autoplay_tracking(Some(tracks.clone()));
+    }
+
     let request = LoadRequest::from_tracks(tracks, options);
     run_spirc_command(spirc, out_error, move |handle| handle.load(request))
 }
 
+/// Loads a context (album/playlist/station URI) for playback.
+///
+/// Use the load request options to pre-apply shuffle/repeat and jump to a
+/// specific track index before playback starts.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_load(
+    spirc: *const cspot_spirc_t,
+    context_uri: *const c_char,
+    options: *const cspot_load_request_options_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    let context_uri = match read_cstr(context_uri, "context_uri", out_error) {
+        Some(value) => value,
+        None => return false,
+    };
+
+    let mut options = if options.is_null() {
+        LoadRequestOptions::default()
+    } else {
+        // Safety: options must be a valid handle allocated by cspot.
+        let handle = unsafe { &*(options as *const LoadRequestOptionsHandle) };
+        handle.options.clone()
+    };
+
+    if !spirc.is_null() {
+        // Safety: spirc must be a valid handle allocated by cspot.
+        let handle = unsafe { &*(spirc as *const SpircHandle) };
+        apply_autoplay_preference(handle, &mut options);
+        let mut guard = handle.core.status.lock().unwrap_or_else(|err| err.into_inner());
+        // Context membership isn't enumerable from the FFI surface, so autoplay
+        // detection is unavailable for context loads; only track-list loads
+        // (`cspot_spirc_load_tracks`) can distinguish continuation playback.
+        guard.reset_autoplay_tracking(None);
+    }
+
+    let request = LoadRequest::from_context(context_uri, options);
+    run_spirc_command(spirc, out_error, move |handle| handle.load(request))
+}
+
+/// Loads the context URI stored on `options` via
+/// `cspot_load_request_options_set_context_uri`.
+///
+/// Equivalent to `cspot_spirc_load`, but takes the context URI from the
+/// options handle instead of as a separate argument, so a single options
+/// value fully describes what to play.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_load_context(
+    spirc: *const cspot_spirc_t,
+    options: *const cspot_load_request_options_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if options.is_null() {
+        write_error(out_error, "options handle was null");
+        return false;
+    }
+    // Safety: options must be a valid handle allocated by cspot.
+    let options_handle = unsafe { &*(options as *const LoadRequestOptionsHandle) };
+    let context_uri = match &options_handle.context_uri {
+        Some(value) => value.clone(),
+        None => {
+            write_error(out_error, "options has no context_uri set");
+            return false;
+        }
+    };
+    let mut load_options = options_handle.options.clone();
+
+    if !spirc.is_null() {
+        // Safety: spirc must be a valid handle allocated by cspot.
+        let handle = unsafe { &*(spirc as *const SpircHandle) };
+        apply_autoplay_preference(handle, &mut load_options);
+        let mut guard = handle.core.status.lock().unwrap_or_else(|err| err.into_inner());
+        guard.reset_autoplay_tracking(None);
+    }
+
+    let request = LoadRequest::from_context(context_uri, load_options);
+    run_spirc_command(spirc, out_error, move |handle| handle.load(request))
+}
+
 /// Reports whether the connect session is currently active/connected.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_spirc_is_connected(spirc: *const cspot_spirc_t) -> bool {
@@ -879,6 +1720,112 @@ pub extern "C" fn cspot_spirc_is_repeat_track_enabled(spirc: *const cspot_spirc_
     }
 }
 
+/// Returns whether the current track is playing from an autoplay/station
+/// continuation rather than the originally loaded track list.
+///
+/// Always returns `false` for context (album/playlist) loads, since their
+/// track membership can't be enumerated from this FFI surface.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_is_autoplay_active(spirc: *const cspot_spirc_t) -> bool {
+    match snapshot_from_spirc(spirc) {
+        Some(snapshot) => snapshot.autoplay_active,
+        None => false,
+    }
+}
+
+/// Sets whether autoplay/station continuation should be requested for
+/// subsequent loads that don't already request it via
+/// `cspot_load_request_options_set_autoplay(true)`; an explicit `false` on
+/// the options handle does not override this preference.
+///
+/// librespot resolves the station/radio context and paginates its track
+/// feed internally once autoplay is requested on a `LoadRequest`; this
+/// preference is applied automatically by `cspot_spirc_load`,
+/// `cspot_spirc_load_tracks`, and `cspot_spirc_load_context` so hosts
+/// don't need to set it on every options handle. Returns `false` if
+/// `spirc` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_set_autoplay(
+    spirc: *const cspot_spirc_t,
+    enabled: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if spirc.is_null() {
+        write_error(out_error, "spirc was null");
+        return false;
+    }
+    // Safety: spirc must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(spirc as *const SpircHandle) };
+    *handle
+        .core
+        .autoplay_enabled
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = enabled;
+    true
+}
+
+/// Returns the host-level autoplay preference set by
+/// `cspot_spirc_set_autoplay`.
+///
+/// This reports the configured preference, not whether the current track
+/// is actually playing via autoplay continuation; use
+/// `cspot_spirc_is_autoplay_active` for that. Returns `false` if `spirc`
+/// is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_is_autoplay_enabled(spirc: *const cspot_spirc_t) -> bool {
+    if spirc.is_null() {
+        return false;
+    }
+    // Safety: spirc must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(spirc as *const SpircHandle) };
+    let enabled = *handle
+        .core
+        .autoplay_enabled
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    enabled
+}
+
+/// Returns how far the current track has been fetched, in milliseconds of
+/// audio, as a lower bound for instantaneous (non-network-bound) seeking.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_buffered_position_ms(spirc: *const cspot_spirc_t) -> u32 {
+    match snapshot_from_spirc(spirc) {
+        Some(snapshot) => snapshot.buffered_position_ms,
+        None => 0,
+    }
+}
+
+/// Returns whether the current track has been buffered through to
+/// end-of-file; seeking anywhere within it will not block on the network.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_is_fully_buffered(spirc: *const cspot_spirc_t) -> bool {
+    match snapshot_from_spirc(spirc) {
+        Some(snapshot) => snapshot.fully_buffered,
+        None => false,
+    }
+}
+
+/// Returns whether the upcoming track has begun/finished gapless preloading.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_is_next_track_preloaded(spirc: *const cspot_spirc_t) -> bool {
+    match snapshot_from_spirc(spirc) {
+        Some(snapshot) => snapshot.next_track_preloaded,
+        None => false,
+    }
+}
+
+/// Returns whether the auto-reconnect supervisor is currently re-establishing
+/// a dropped session. Always `false` when auto-reconnect is disabled.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_is_reconnecting(spirc: *const cspot_spirc_t) -> bool {
+    match snapshot_from_spirc(spirc) {
+        Some(snapshot) => snapshot.reconnecting,
+        None => false,
+    }
+}
+
 /// Returns the current track Spotify ID, if available.
 ///
 /// The returned string is heap-allocated and must be freed with `cspot_string_free`.
@@ -935,6 +1882,81 @@ pub extern "C" fn cspot_spirc_current_track_title(spirc: *const cspot_spirc_t) -
     string_to_owned_ptr(value)
 }
 
+/// Registers a callback to receive push-based player/Spirc events.
+///
+/// Replaces any previously registered callback. Pass `None` for `callback`
+/// to stop receiving events. Returns `false` if `spirc` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_set_event_callback(
+    spirc: *const cspot_spirc_t,
+    callback: cspot_spirc_event_callback_t,
+    user_data: *mut c_void,
+) -> bool {
+    if spirc.is_null() {
+        return false;
+    }
+    // Safety: spirc must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(spirc as *const SpircHandle) };
+    let mut guard = handle
+        .core
+        .event_callback
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    *guard = callback.map(|callback| EventCallback {
+        callback: Some(callback),
+        user_data: user_data as usize,
+    });
+    true
+}
+
+/// Sets the policy applied when the current track is unplayable.
+///
+/// Defaults to `CSPOT_UNPLAYABLE_STOP`, matching Spirc's own behavior.
+/// Returns `false` if `spirc` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_set_unplayable_policy(
+    spirc: *const cspot_spirc_t,
+    policy: cspot_unplayable_policy_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    if spirc.is_null() {
+        write_error(out_error, "spirc was null");
+        return false;
+    }
+    // Safety: spirc must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(spirc as *const SpircHandle) };
+    *handle
+        .core
+        .unplayable_policy
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = UnplayablePolicy::from(policy);
+    true
+}
+
+/// Returns the currently configured unplayable-track policy.
+///
+/// Returns `CSPOT_UNPLAYABLE_STOP` if `spirc` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_spirc_unplayable_policy(
+    spirc: *const cspot_spirc_t,
+) -> cspot_unplayable_policy_t {
+    if spirc.is_null() {
+        return cspot_unplayable_policy_t::CSPOT_UNPLAYABLE_STOP;
+    }
+    // Safety: spirc must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(spirc as *const SpircHandle) };
+    let policy = *handle
+        .core
+        .unplayable_policy
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    match policy {
+        UnplayablePolicy::Stop => cspot_unplayable_policy_t::CSPOT_UNPLAYABLE_STOP,
+        UnplayablePolicy::Skip => cspot_unplayable_policy_t::CSPOT_UNPLAYABLE_SKIP,
+    }
+}
+
 /// Requests a clean Connect shutdown.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_spirc_shutdown(
@@ -997,5 +2019,13 @@ pub extern "C" fn cspot_spirc_free(spirc: *mut cspot_spirc_t) {
     }
     // Safety: spirc must be a valid handle allocated by cspot.
     let handle = unsafe { Box::from_raw(spirc as *mut SpircHandle) };
-    handle.status_task.abort();
+    if let Some(supervisor_task) = &handle.supervisor_task {
+        supervisor_task.abort();
+    }
+    handle
+        .core
+        .status_task
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .abort();
 }
@@ -1,11 +1,14 @@
 use std::ffi::CString;
+use std::fs;
 use std::os::raw::c_char;
 use std::panic::AssertUnwindSafe;
 use std::ptr;
+use std::slice;
 
-use data_encoding::HEXLOWER;
+use data_encoding::{BASE64, HEXLOWER};
 use futures_util::StreamExt;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
 
 use librespot::core::SessionConfig;
@@ -103,6 +106,31 @@ impl From<AuthenticationType> for cspot_auth_type_t {
     }
 }
 
+impl TryFrom<cspot_auth_type_t> for AuthenticationType {
+    type Error = String;
+
+    fn try_from(value: cspot_auth_type_t) -> Result<Self, Self::Error> {
+        match value {
+            cspot_auth_type_t::CSPOT_AUTH_TYPE_USER_PASS => Ok(Self::AUTHENTICATION_USER_PASS),
+            cspot_auth_type_t::CSPOT_AUTH_TYPE_STORED_SPOTIFY_CREDENTIALS => {
+                Ok(Self::AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS)
+            }
+            cspot_auth_type_t::CSPOT_AUTH_TYPE_STORED_FACEBOOK_CREDENTIALS => {
+                Ok(Self::AUTHENTICATION_STORED_FACEBOOK_CREDENTIALS)
+            }
+            cspot_auth_type_t::CSPOT_AUTH_TYPE_SPOTIFY_TOKEN => {
+                Ok(Self::AUTHENTICATION_SPOTIFY_TOKEN)
+            }
+            cspot_auth_type_t::CSPOT_AUTH_TYPE_FACEBOOK_TOKEN => {
+                Ok(Self::AUTHENTICATION_FACEBOOK_TOKEN)
+            }
+            cspot_auth_type_t::CSPOT_AUTH_TYPE_INVALID => {
+                Err("auth_type was CSPOT_AUTH_TYPE_INVALID".to_string())
+            }
+        }
+    }
+}
+
 /// Result of polling discovery for the next credential event.
 #[allow(non_camel_case_types)]
 #[repr(C)]
@@ -171,37 +199,97 @@ pub extern "C" fn cspot_device_id_from_name(
     }
 }
 
+/// mDNS backend named in `cspot_discovery_config_t`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub enum cspot_mdns_backend_t {
+    CSPOT_MDNS_LIBMDNS = 0,
+    CSPOT_MDNS_DNS_SD = 1,
+}
+
+fn mdns_backend_available(backend: cspot_mdns_backend_t) -> bool {
+    match backend {
+        cspot_mdns_backend_t::CSPOT_MDNS_LIBMDNS => cfg!(feature = "with-libmdns"),
+        cspot_mdns_backend_t::CSPOT_MDNS_DNS_SD => cfg!(feature = "with-dns-sd"),
+    }
+}
+
+fn mdns_backend_name(backend: cspot_mdns_backend_t) -> &'static str {
+    match backend {
+        cspot_mdns_backend_t::CSPOT_MDNS_LIBMDNS => "libmdns",
+        cspot_mdns_backend_t::CSPOT_MDNS_DNS_SD => "DNS-SD",
+    }
+}
+
+/// Configuration for `cspot_discovery_create`.
+///
+/// `zeroconf_port` pins the advertised discovery port; pass 0 for an ephemeral
+/// port. `mdns_backend` must name a backend cspot was built with, otherwise
+/// `cspot_discovery_create` fails. The value is only checked; it is not
+/// passed on to the discovery builder.
+#[repr(C)]
+pub struct cspot_discovery_config_t {
+    pub device_id: *const c_char,
+    pub client_id: *const c_char,
+    pub name: *const c_char,
+    pub device_type: cspot_device_type_t,
+    pub zeroconf_port: u16,
+    pub mdns_backend: cspot_mdns_backend_t,
+}
+
 /// Starts a discovery service.
 ///
+/// Advertises this device over mDNS so official Spotify apps can find it and
+/// hand off a user's session; call `cspot_discovery_next` in a loop to
+/// retrieve the `cspot_credentials_t` each handoff produces, then pass them
+/// to `cspot_session_create`/`cspot_spirc_create` to come online as a
+/// Connect endpoint.
+///
 /// This call blocks while the discovery server is started. On success, the returned
 /// handle must be released with `cspot_discovery_free`.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_discovery_create(
-    device_id: *const c_char,
-    client_id: *const c_char,
-    name: *const c_char,
-    device_type: cspot_device_type_t,
+    config: *const cspot_discovery_config_t,
     out_error: *mut *mut cspot_error_t,
 ) -> *mut cspot_discovery_t {
     clear_error(out_error);
-    let device_id = match read_cstr(device_id, "device_id", out_error) {
+    if config.is_null() {
+        write_error(out_error, "config was null");
+        return ptr::null_mut();
+    }
+    // Safety: config must point to a valid cspot_discovery_config_t.
+    let config = unsafe { &*config };
+    let device_id = match read_cstr(config.device_id, "device_id", out_error) {
         Some(value) => value,
         None => return ptr::null_mut(),
     };
-    let client_id = match read_cstr(client_id, "client_id", out_error) {
+    let client_id = match read_cstr(config.client_id, "client_id", out_error) {
         Some(value) => value,
         None => return ptr::null_mut(),
     };
-    let name = match read_cstr(name, "name", out_error) {
+    let name = match read_cstr(config.name, "name", out_error) {
         Some(value) => value,
         None => return ptr::null_mut(),
     };
+    if !mdns_backend_available(config.mdns_backend) {
+        write_error(
+            out_error,
+            format!(
+                "cspot was not built with the {} mDNS backend",
+                mdns_backend_name(config.mdns_backend)
+            ),
+        );
+        return ptr::null_mut();
+    }
+    let device_type = config.device_type;
+    let zeroconf_port = config.zeroconf_port;
 
     let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
         runtime().block_on(async {
             Discovery::builder(device_id, client_id)
                 .name(name)
                 .device_type(device_type.into())
+                .zeroconf_port(zeroconf_port)
                 .launch()
         })
     }));
@@ -220,6 +308,29 @@ pub extern "C" fn cspot_discovery_create(
     }
 }
 
+/// Starts zeroconf discovery, advertising this device for Spotify Connect
+/// handoff. Equivalent to `cspot_discovery_create`, under the Connect
+/// subsystem's `cspot_connect_*`/`cspot_discovery_*` naming.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_discovery_start(
+    config: *const cspot_discovery_config_t,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_discovery_t {
+    cspot_discovery_create(config, out_error)
+}
+
+/// Returns the zeroconf port the discovery service actually bound to, which is
+/// useful when `zeroconf_port` was passed as 0 (ephemeral) in the config.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_discovery_port(discovery: *const cspot_discovery_t) -> u16 {
+    if discovery.is_null() {
+        return 0;
+    }
+    // Safety: discovery must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(discovery as *const DiscoveryHandle) };
+    handle.discovery.port()
+}
+
 /// Blocks until the next credential event or until discovery stops.
 ///
 /// Returns `CSPOT_DISCOVERY_NEXT_CREDENTIALS` when credentials are available,
@@ -340,6 +451,210 @@ pub extern "C" fn cspot_credentials_auth_data(
     handle.credentials.auth_data.as_ptr()
 }
 
+/// Builds credentials from a previously obtained Spotify access token.
+///
+/// `username` may be null, since access-token credentials do not always carry one.
+/// The returned handle must be released with `cspot_credentials_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_credentials_with_access_token(
+    username: *const c_char,
+    token: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_credentials_t {
+    clear_error(out_error);
+    let token = match read_cstr(token, "token", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let mut credentials = Credentials::with_access_token(token);
+    if !username.is_null() {
+        credentials.username = match read_cstr(username, "username", out_error) {
+            Some(value) => Some(value),
+            None => return ptr::null_mut(),
+        };
+    }
+    credentials_handle_from(credentials)
+}
+
+/// Builds credentials from a stored authentication blob, as persisted by
+/// `cspot_credentials_save` or the original client that produced it.
+///
+/// `data` must point to `len` bytes and is copied; the caller retains ownership.
+/// The returned handle must be released with `cspot_credentials_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_credentials_with_stored(
+    username: *const c_char,
+    auth_type: cspot_auth_type_t,
+    data: *const u8,
+    len: usize,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_credentials_t {
+    clear_error(out_error);
+    let auth_type = match AuthenticationType::try_from(auth_type) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+    if len > 0 && data.is_null() {
+        write_error(out_error, "data was null");
+        return ptr::null_mut();
+    }
+    // Safety: data is valid for len bytes, as guaranteed by the caller.
+    let auth_data = if len == 0 {
+        Vec::new()
+    } else {
+        unsafe { slice::from_raw_parts(data, len) }.to_vec()
+    };
+    let username = if username.is_null() {
+        None
+    } else {
+        match read_cstr(username, "username", out_error) {
+            Some(value) => Some(value),
+            None => return ptr::null_mut(),
+        }
+    };
+    credentials_handle_from(Credentials {
+        username,
+        auth_type,
+        auth_data,
+    })
+}
+
+/// On-disk layout matching librespot's credential cache files.
+#[derive(Serialize, Deserialize)]
+struct CachedCredentials {
+    username: Option<String>,
+    #[serde(rename = "type")]
+    auth_type: String,
+    credentials: String,
+}
+
+fn auth_type_cache_name(auth_type: AuthenticationType) -> &'static str {
+    match auth_type {
+        AuthenticationType::AUTHENTICATION_USER_PASS => "AUTHENTICATION_USER_PASS",
+        AuthenticationType::AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS => {
+            "AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS"
+        }
+        AuthenticationType::AUTHENTICATION_STORED_FACEBOOK_CREDENTIALS => {
+            "AUTHENTICATION_STORED_FACEBOOK_CREDENTIALS"
+        }
+        AuthenticationType::AUTHENTICATION_SPOTIFY_TOKEN => "AUTHENTICATION_SPOTIFY_TOKEN",
+        AuthenticationType::AUTHENTICATION_FACEBOOK_TOKEN => "AUTHENTICATION_FACEBOOK_TOKEN",
+    }
+}
+
+fn auth_type_from_cache_name(name: &str) -> Result<AuthenticationType, String> {
+    match name {
+        "AUTHENTICATION_USER_PASS" => Ok(AuthenticationType::AUTHENTICATION_USER_PASS),
+        "AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS" => {
+            Ok(AuthenticationType::AUTHENTICATION_STORED_SPOTIFY_CREDENTIALS)
+        }
+        "AUTHENTICATION_STORED_FACEBOOK_CREDENTIALS" => {
+            Ok(AuthenticationType::AUTHENTICATION_STORED_FACEBOOK_CREDENTIALS)
+        }
+        "AUTHENTICATION_SPOTIFY_TOKEN" => Ok(AuthenticationType::AUTHENTICATION_SPOTIFY_TOKEN),
+        "AUTHENTICATION_FACEBOOK_TOKEN" => Ok(AuthenticationType::AUTHENTICATION_FACEBOOK_TOKEN),
+        other => Err(format!("unknown credential auth type `{other}`")),
+    }
+}
+
+/// Persists credentials to `path` in the same JSON layout librespot's on-disk
+/// credential cache uses, so they can be reloaded on a later launch without
+/// repeating discovery/OAuth.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_credentials_save(
+    credentials: *const cspot_credentials_t,
+    path: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    let credentials = match credentials_from_handle(credentials) {
+        Some(value) => value,
+        None => {
+            write_error(out_error, "credentials handle was null");
+            return false;
+        }
+    };
+    let path = match read_cstr(path, "path", out_error) {
+        Some(value) => value,
+        None => return false,
+    };
+
+    let cached = CachedCredentials {
+        username: credentials.username,
+        auth_type: auth_type_cache_name(credentials.auth_type).to_string(),
+        credentials: BASE64.encode(&credentials.auth_data),
+    };
+    let json = match serde_json::to_string_pretty(&cached) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, format!("failed to serialize credentials: {err}"));
+            return false;
+        }
+    };
+    if let Err(err) = fs::write(&path, json) {
+        write_error(out_error, format!("failed to write `{path}`: {err}"));
+        return false;
+    }
+    true
+}
+
+/// Loads credentials previously written by `cspot_credentials_save`.
+///
+/// The returned handle must be released with `cspot_credentials_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_credentials_load(
+    path: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_credentials_t {
+    clear_error(out_error);
+    let path = match read_cstr(path, "path", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let json = match fs::read_to_string(&path) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, format!("failed to read `{path}`: {err}"));
+            return ptr::null_mut();
+        }
+    };
+    let cached: CachedCredentials = match serde_json::from_str(&json) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(
+                out_error,
+                format!("failed to parse credentials at `{path}`: {err}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let auth_type = match auth_type_from_cache_name(&cached.auth_type) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+    let auth_data = match BASE64.decode(cached.credentials.as_bytes()) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(
+                out_error,
+                format!("failed to decode credentials at `{path}`: {err}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    credentials_handle_from(Credentials {
+        username: cached.username,
+        auth_type,
+        auth_data,
+    })
+}
+
 /// Frees a credentials handle.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_credentials_free(credentials: *mut cspot_credentials_t) {
@@ -385,3 +700,8 @@ pub(crate) fn credentials_from_handle(
     let handle = unsafe { &*(credentials as *const CredentialsHandle) };
     Some(handle.credentials.clone())
 }
+
+/// Wraps librespot `Credentials` obtained outside of discovery (e.g. OAuth) into a handle.
+pub(crate) fn credentials_handle_from(credentials: Credentials) -> *mut cspot_credentials_t {
+    Box::into_raw(Box::new(CredentialsHandle::new(credentials))) as *mut cspot_credentials_t
+}
@@ -1,5 +1,18 @@
 //! C FFI entry points for cspot.
 
+mod android;
+mod connect;
+mod discovery;
+mod error;
+mod ffi;
+mod logging;
+mod oauth;
+mod playback;
+mod player;
+mod runtime;
+mod session;
+mod uri;
+
 /// Temporary placeholder to validate C bindings wiring.
 ///
 /// Returns a fixed marker value that can be checked from C.
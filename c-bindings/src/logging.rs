@@ -1,13 +1,16 @@
 //! Logging configuration for cspot's C bindings.
 
-use std::ffi::CStr;
+use std::collections::VecDeque;
+use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_void};
 use std::ptr;
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::atomic::{AtomicU8, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Once, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use log::{Level, LevelFilter, Log, Metadata, Record};
 use once_cell::sync::Lazy;
+use regex::Regex;
 
 use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
 
@@ -18,6 +21,9 @@ const LOGGER_STATE_FAILED: u8 = 2;
 static LOGGER_STATE: AtomicU8 = AtomicU8::new(LOGGER_STATE_UNINIT);
 static LOGGER_INIT: Once = Once::new();
 static CSPOT_LOGGER: Lazy<CspotLogger> = Lazy::new(CspotLogger::new);
+static LOG_STORE: Lazy<LogStore> = Lazy::new(LogStore::new);
+static LISTENERS: Lazy<RwLock<Vec<Listener>>> = Lazy::new(|| RwLock::new(Vec::new()));
+static NEXT_LISTENER_ID: AtomicU64 = AtomicU64::new(1);
 
 /// Log level values for cspot logging.
 #[allow(non_camel_case_types)]
@@ -93,9 +99,19 @@ pub struct cspot_log_config_t {
     pub user_data: *mut c_void,
 }
 
+/// How a directive's target is matched against a record's target string.
+///
+/// `Prefix` keeps the original `starts_with` behavior; `Regex` is written
+/// as `/<pattern>=<level>` in a filter spec.
+#[derive(Clone)]
+enum TargetMatcher {
+    Prefix(String),
+    Regex(Regex),
+}
+
 #[derive(Clone)]
 struct TargetFilter {
-    target: String,
+    matcher: TargetMatcher,
     level: LevelFilter,
 }
 
@@ -111,13 +127,29 @@ impl LogFilter {
             default: LevelFilter::Off,
             directives: vec![
                 TargetFilter {
-                    target: "librespot".to_string(),
+                    matcher: TargetMatcher::Prefix("librespot".to_string()),
                     level,
                 },
             ],
         }
     }
 
+    fn directive_from_target(target: &str, level: LevelFilter) -> Result<TargetFilter, String> {
+        if let Some(pattern) = target.strip_prefix('/') {
+            let regex = Regex::new(pattern)
+                .map_err(|err| format!("invalid regex target `/{pattern}`: {err}"))?;
+            Ok(TargetFilter {
+                matcher: TargetMatcher::Regex(regex),
+                level,
+            })
+        } else {
+            Ok(TargetFilter {
+                matcher: TargetMatcher::Prefix(target.to_string()),
+                level,
+            })
+        }
+    }
+
     fn parse(spec: &str) -> Result<Self, String> {
         let mut default = LevelFilter::Off;
         let mut directives = Vec::new();
@@ -141,40 +173,52 @@ impl LogFilter {
                 }
                 let level =
                     parse_level(level_str).ok_or_else(|| format!("invalid level `{level_str}`"))?;
-                directives.push(TargetFilter {
-                    target: left.to_string(),
-                    level,
-                });
+                directives.push(Self::directive_from_target(left, level)?);
             } else if let Some(level) = parse_level(left) {
                 default = level;
             } else {
-                directives.push(TargetFilter {
-                    target: left.to_string(),
-                    level: LevelFilter::Trace,
-                });
+                directives.push(Self::directive_from_target(left, LevelFilter::Trace)?);
             }
         }
 
         Ok(Self { default, directives })
     }
 
-    fn enabled(&self, metadata: &Metadata) -> bool {
-        let target = metadata.target();
-        let mut best_level = self.default;
-        let mut best_len = 0usize;
+    /// Resolves the level to apply to `metadata`.
+    ///
+    /// Regex directives take precedence over prefix directives, regardless
+    /// of target length, since writing a regex is a more specific, deliberate
+    /// choice; among directives of the same kind, longest-prefix wins for
+    /// `Prefix` matchers and the last matching directive in the spec wins for
+    /// `Regex` matchers (mirroring the one-pass, later-wins evaluation order
+    /// `env_logger`-style specs use).
+    fn resolve_level(&self, target: &str) -> LevelFilter {
+        let mut best_prefix_level = self.default;
+        let mut best_prefix_len = 0usize;
+        let mut best_regex_level: Option<LevelFilter> = None;
 
         for directive in &self.directives {
-            if target.starts_with(&directive.target) {
-                let len = directive.target.len();
-                if len >= best_len {
-                    best_len = len;
-                    best_level = directive.level;
+            match &directive.matcher {
+                TargetMatcher::Prefix(prefix) => {
+                    if target.starts_with(prefix.as_str()) && prefix.len() >= best_prefix_len {
+                        best_prefix_len = prefix.len();
+                        best_prefix_level = directive.level;
+                    }
+                }
+                TargetMatcher::Regex(regex) => {
+                    if regex.is_match(target) {
+                        best_regex_level = Some(directive.level);
+                    }
                 }
             }
         }
 
+        best_regex_level.unwrap_or(best_prefix_level)
+    }
+
+    fn enabled(&self, metadata: &Metadata) -> bool {
         let record_level = metadata.level().to_level_filter();
-        record_level <= best_level
+        record_level <= self.resolve_level(metadata.target())
     }
 
     fn max_level(&self) -> LevelFilter {
@@ -233,13 +277,185 @@ impl CspotLogger {
     }
 }
 
+/// A log record retained in the in-memory ring buffer.
+#[derive(Clone)]
+struct StoredRecord {
+    level: cspot_log_level_t,
+    target: String,
+    message: String,
+    module_path: Option<String>,
+    file: Option<String>,
+    line: u32,
+    timestamp_unix_ms: u64,
+}
+
+impl StoredRecord {
+    /// Rough accounting size used against the store's byte budget; not an
+    /// exact memory footprint, just proportional to it.
+    fn approx_size(&self) -> usize {
+        self.target.len()
+            + self.message.len()
+            + self.module_path.as_deref().map_or(0, str::len)
+            + self.file.as_deref().map_or(0, str::len)
+            + 32
+    }
+}
+
+/// Bounded in-memory store of recent log records, queryable via
+/// `cspot_log_query_records`.
+///
+/// Disabled (capacity 0) by default; enable with
+/// `cspot_log_set_memory_capacity`.
+struct LogStore {
+    records: RwLock<VecDeque<StoredRecord>>,
+    max_records: AtomicUsize,
+    max_bytes: AtomicUsize,
+    current_bytes: AtomicUsize,
+}
+
+impl LogStore {
+    fn new() -> Self {
+        Self {
+            records: RwLock::new(VecDeque::new()),
+            max_records: AtomicUsize::new(0),
+            max_bytes: AtomicUsize::new(0),
+            current_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    fn set_capacity(&self, max_records: usize, max_bytes: usize) {
+        self.max_records.store(max_records, Ordering::SeqCst);
+        self.max_bytes.store(max_bytes, Ordering::SeqCst);
+        self.trim();
+    }
+
+    fn push(&self, record: StoredRecord) {
+        if self.max_records.load(Ordering::SeqCst) == 0 {
+            return;
+        }
+        let size = record.approx_size();
+        {
+            let mut guard = self.records.write().unwrap_or_else(|err| err.into_inner());
+            guard.push_back(record);
+        }
+        self.current_bytes.fetch_add(size, Ordering::SeqCst);
+        self.trim();
+    }
+
+    fn trim(&self) {
+        let max_records = self.max_records.load(Ordering::SeqCst);
+        let max_bytes = self.max_bytes.load(Ordering::SeqCst);
+        let mut guard = self.records.write().unwrap_or_else(|err| err.into_inner());
+        while guard.len() > max_records
+            || (max_bytes > 0 && self.current_bytes.load(Ordering::SeqCst) > max_bytes)
+        {
+            let Some(removed) = guard.pop_front() else {
+                break;
+            };
+            self.current_bytes
+                .fetch_sub(removed.approx_size(), Ordering::SeqCst);
+        }
+    }
+
+    fn query(&self, filter: &QueryFilter) -> Vec<StoredRecord> {
+        let guard = self.records.read().unwrap_or_else(|err| err.into_inner());
+        let matching: Vec<StoredRecord> = guard
+            .iter()
+            .filter(|record| filter.matches(record))
+            .cloned()
+            .collect();
+        drop(guard);
+
+        if filter.limit == 0 || matching.len() <= filter.limit {
+            matching
+        } else {
+            matching[matching.len() - filter.limit..].to_vec()
+        }
+    }
+}
+
+struct QueryFilter {
+    min_level: LevelFilter,
+    target_prefix: Option<String>,
+    not_before_unix_ms: u64,
+    limit: usize,
+}
+
+impl QueryFilter {
+    fn matches(&self, record: &StoredRecord) -> bool {
+        let record_level: LevelFilter = record.level.into();
+        if record_level > self.min_level {
+            return false;
+        }
+        if record.timestamp_unix_ms < self.not_before_unix_ms {
+            return false;
+        }
+        if let Some(prefix) = &self.target_prefix {
+            if !record.target.starts_with(prefix.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn current_unix_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// A single subscriber registered via `cspot_log_add_listener`.
+///
+/// Independent from the single callback/stderr sink configured by
+/// `cspot_log_init`; any number of listeners may be registered at once,
+/// each with its own severity and target-prefix filter.
+struct Listener {
+    id: u64,
+    min_level: LevelFilter,
+    target_prefix: Option<String>,
+    callback: cspot_log_callback_t,
+    user_data: usize,
+}
+
+impl Listener {
+    fn matches(&self, metadata: &Metadata) -> bool {
+        if metadata.level().to_level_filter() > self.min_level {
+            return false;
+        }
+        match &self.target_prefix {
+            Some(prefix) => metadata.target().starts_with(prefix.as_str()),
+            None => true,
+        }
+    }
+}
+
+/// Recomputes the global `log` crate max level as the max of the configured
+/// filter's level and every registered listener's level, so no listener
+/// silently misses records the global filter would otherwise suppress.
+fn recompute_max_level() {
+    let mut max_level = CSPOT_LOGGER.with_config(|config| config.filter.max_level());
+    let guard = LISTENERS.read().unwrap_or_else(|err| err.into_inner());
+    for listener in guard.iter() {
+        if listener.min_level > max_level {
+            max_level = listener.min_level;
+        }
+    }
+    log::set_max_level(max_level);
+}
+
 impl Log for CspotLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        self.with_config(|config| config.filter.enabled(metadata))
+        if self.with_config(|config| config.filter.enabled(metadata)) {
+            return true;
+        }
+        let guard = LISTENERS.read().unwrap_or_else(|err| err.into_inner());
+        guard.iter().any(|listener| listener.matches(metadata))
     }
 
     fn log(&self, record: &Record) {
-        let (callback, user_data, enabled) = self.with_config(|config| {
+        let (callback, user_data, global_enabled) = self.with_config(|config| {
             (
                 config.callback,
                 config.user_data,
@@ -247,26 +463,55 @@ impl Log for CspotLogger {
             )
         });
 
-        if !enabled {
+        let matching_listeners: Vec<(cspot_log_callback_t, usize)> = {
+            let guard = LISTENERS.read().unwrap_or_else(|err| err.into_inner());
+            guard
+                .iter()
+                .filter(|listener| listener.matches(record.metadata()))
+                .map(|listener| (listener.callback, listener.user_data))
+                .collect()
+        };
+
+        if !global_enabled && matching_listeners.is_empty() {
+            return;
+        }
+
+        LOG_STORE.push(StoredRecord {
+            level: cspot_log_level_t::from(record.level()),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            module_path: record.module_path().map(str::to_string),
+            file: record.file().map(str::to_string),
+            line: record.line().unwrap_or(0),
+            timestamp_unix_ms: current_unix_ms(),
+        });
+
+        let level = cspot_log_level_t::from(record.level());
+        let target = cstring_from_str_lossy(record.target());
+        let message = cstring_from_str_lossy(&record.args().to_string());
+        let module_path = record.module_path().map(cstring_from_str_lossy);
+        let file = record.file().map(cstring_from_str_lossy);
+        let c_record = cspot_log_record_t {
+            level,
+            target: target.as_ptr(),
+            message: message.as_ptr(),
+            module_path: module_path.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+            file: file.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+            line: record.line().unwrap_or(0),
+        };
+
+        for (listener_callback, listener_user_data) in matching_listeners {
+            if let Some(listener_callback) = listener_callback {
+                listener_callback(&c_record, listener_user_data as *mut c_void);
+            }
+        }
+
+        if !global_enabled {
             return;
         }
 
         if let Some(callback) = callback {
-            let user_data = user_data as *mut c_void;
-            let level = cspot_log_level_t::from(record.level());
-            let target = cstring_from_str_lossy(record.target());
-            let message = cstring_from_str_lossy(&record.args().to_string());
-            let module_path = record.module_path().map(cstring_from_str_lossy);
-            let file = record.file().map(cstring_from_str_lossy);
-            let record = cspot_log_record_t {
-                level,
-                target: target.as_ptr(),
-                message: message.as_ptr(),
-                module_path: module_path.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
-                file: file.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
-                line: record.line().unwrap_or(0),
-            };
-            callback(&record, user_data);
+            callback(&c_record, user_data as *mut c_void);
         } else {
             eprintln!(
                 "{} {}: {}",
@@ -392,8 +637,188 @@ pub extern "C" fn cspot_log_init(
     let callback = config.and_then(|config| config.callback);
     let user_data = config.map(|config| config.user_data as usize).unwrap_or(0);
 
-    let max_level = filter.max_level();
     CSPOT_LOGGER.update(LoggerConfig::new(filter, callback, user_data));
-    log::set_max_level(max_level);
+    recompute_max_level();
+    true
+}
+
+/// Registers an independent log listener with its own severity and
+/// target-prefix filter.
+///
+/// Any number of listeners may be registered alongside each other and
+/// alongside the single callback/stderr sink configured by `cspot_log_init`.
+/// `target_prefix` may be null to match any target. Returns an id to pass to
+/// `cspot_log_remove_listener`, or 0 if `callback` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_log_add_listener(
+    min_level: cspot_log_level_t,
+    target_prefix: *const c_char,
+    callback: cspot_log_callback_t,
+    user_data: *mut c_void,
+) -> u64 {
+    if callback.is_none() {
+        return 0;
+    }
+    let id = NEXT_LISTENER_ID.fetch_add(1, Ordering::SeqCst);
+    let listener = Listener {
+        id,
+        min_level: min_level.into(),
+        target_prefix: read_optional_cstr(target_prefix),
+        callback,
+        user_data: user_data as usize,
+    };
+
+    {
+        let mut guard = LISTENERS.write().unwrap_or_else(|err| err.into_inner());
+        guard.push(listener);
+    }
+    recompute_max_level();
+    id
+}
+
+/// Unregisters a listener previously registered with `cspot_log_add_listener`.
+///
+/// Returns `false` if `id` doesn't match a currently registered listener.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_log_remove_listener(id: u64) -> bool {
+    let removed = {
+        let mut guard = LISTENERS.write().unwrap_or_else(|err| err.into_inner());
+        let len_before = guard.len();
+        guard.retain(|listener| listener.id != id);
+        guard.len() != len_before
+    };
+    if removed {
+        recompute_max_level();
+    }
+    removed
+}
+
+/// Enables (or resizes) the in-memory log record store queried by
+/// `cspot_log_query_records`.
+///
+/// `max_records` bounds the number of retained records; `max_bytes` (0 for
+/// no byte limit) additionally bounds retained records by approximate
+/// combined size. Pass `max_records == 0` to disable the store and drop
+/// everything currently retained. Oldest records are evicted first.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_log_set_memory_capacity(max_records: usize, max_bytes: usize) {
+    LOG_STORE.set_capacity(max_records, max_bytes);
+}
+
+/// Filter applied by `cspot_log_query_records`.
+///
+/// `target_prefix` may be null to match any target. `not_before_unix_ms`
+/// excludes records older than the given Unix timestamp (0 for no lower
+/// bound). `limit` caps the number of (most recent, matching) records
+/// returned; 0 means no limit.
+#[repr(C)]
+pub struct cspot_log_query_filter_t {
+    pub min_level: cspot_log_level_t,
+    pub target_prefix: *const c_char,
+    pub not_before_unix_ms: u64,
+    pub limit: usize,
+}
+
+/// An owned log record returned by `cspot_log_query_records`.
+///
+/// String fields must not be freed individually; release the whole array
+/// with `cspot_log_records_free`. `module_path` and `file` are null when
+/// unavailable.
+#[repr(C)]
+pub struct cspot_stored_log_record_t {
+    pub level: cspot_log_level_t,
+    pub target: *mut c_char,
+    pub message: *mut c_char,
+    pub module_path: *mut c_char,
+    pub file: *mut c_char,
+    pub line: u32,
+    pub timestamp_unix_ms: u64,
+}
+
+fn owned_cstring(value: String) -> *mut c_char {
+    CString::new(value.replace('\0', "")).unwrap_or_default().into_raw()
+}
+
+/// Queries the in-memory log record store, returning matching records in
+/// chronological order (oldest first).
+///
+/// `filter` may be null to match everything with no limit. On success, the
+/// returned array must be released with `cspot_log_records_free`. Returns
+/// `false` only if `out_records`/`out_count` are null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_log_query_records(
+    filter: *const cspot_log_query_filter_t,
+    out_records: *mut *mut cspot_stored_log_record_t,
+    out_count: *mut usize,
+) -> bool {
+    if out_records.is_null() || out_count.is_null() {
+        return false;
+    }
+
+    let query = match unsafe { filter.as_ref() } {
+        Some(filter) => QueryFilter {
+            min_level: filter.min_level.into(),
+            target_prefix: read_optional_cstr(filter.target_prefix),
+            not_before_unix_ms: filter.not_before_unix_ms,
+            limit: filter.limit,
+        },
+        None => QueryFilter {
+            min_level: LevelFilter::Trace,
+            target_prefix: None,
+            not_before_unix_ms: 0,
+            limit: 0,
+        },
+    };
+
+    let matching = LOG_STORE.query(&query);
+    let mut records: Vec<cspot_stored_log_record_t> = matching
+        .into_iter()
+        .map(|record| cspot_stored_log_record_t {
+            level: record.level,
+            target: owned_cstring(record.target),
+            message: owned_cstring(record.message),
+            module_path: record.module_path.map_or(ptr::null_mut(), owned_cstring),
+            file: record.file.map_or(ptr::null_mut(), owned_cstring),
+            line: record.line,
+            timestamp_unix_ms: record.timestamp_unix_ms,
+        })
+        .collect();
+
+    records.shrink_to_fit();
+    let count = records.len();
+    let ptr = records.as_mut_ptr();
+    std::mem::forget(records);
+
+    // Safety: out_records/out_count were checked non-null above.
+    unsafe {
+        *out_records = ptr;
+        *out_count = count;
+    }
     true
 }
+
+/// Frees an array of records returned by `cspot_log_query_records`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_log_records_free(records: *mut cspot_stored_log_record_t, count: usize) {
+    if records.is_null() {
+        return;
+    }
+    // Safety: records/count must come from `cspot_log_query_records`.
+    unsafe {
+        let records = Vec::from_raw_parts(records, count, count);
+        for record in records {
+            if !record.target.is_null() {
+                drop(CString::from_raw(record.target));
+            }
+            if !record.message.is_null() {
+                drop(CString::from_raw(record.message));
+            }
+            if !record.module_path.is_null() {
+                drop(CString::from_raw(record.module_path));
+            }
+            if !record.file.is_null() {
+                drop(CString::from_raw(record.file));
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! C bindings for Spotify's OAuth authorization-code (PKCE) login flow.
+//!
+//! This lets a C caller authenticate headlessly, without a phone/desktop client
+//! completing discovery over the LAN first.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::os::raw::c_char;
+use std::panic::AssertUnwindSafe;
+use std::ptr;
+
+use data_encoding::BASE64URL_NOPAD;
+use rand::RngCore;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::task::JoinHandle;
+
+use librespot::discovery::Credentials;
+
+use crate::discovery::{credentials_handle_from, cspot_credentials_t};
+use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
+use crate::ffi::read_cstr;
+use crate::runtime::runtime;
+
+const AUTHORIZE_URL: &str = "https://accounts.spotify.com/authorize";
+const TOKEN_URL: &str = "https://accounts.spotify.com/api/token";
+
+/// Opaque handle for an in-flight, non-blocking OAuth login.
+#[allow(non_camel_case_types)]
+pub struct cspot_oauth_pending_t;
+
+/// Result of polling a pending OAuth login.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub enum cspot_oauth_poll_result_t {
+    CSPOT_OAUTH_POLL_PENDING = 0,
+    CSPOT_OAUTH_POLL_READY = 1,
+    CSPOT_OAUTH_POLL_ERROR = 2,
+}
+
+struct PkceChallenge {
+    verifier: String,
+    challenge: String,
+}
+
+fn generate_pkce() -> PkceChallenge {
+    let mut verifier_bytes = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut verifier_bytes);
+    let verifier = BASE64URL_NOPAD.encode(&verifier_bytes);
+    let challenge = BASE64URL_NOPAD.encode(Sha256::digest(verifier.as_bytes()).as_slice());
+    PkceChallenge { verifier, challenge }
+}
+
+fn redirect_port(redirect_uri: &str) -> Result<u16, String> {
+    let authority = redirect_uri
+        .split("://")
+        .nth(1)
+        .ok_or_else(|| format!("redirect_uri `{redirect_uri}` is missing a scheme"))?
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    authority
+        .rsplit(':')
+        .next()
+        .filter(|_| authority.contains(':'))
+        .ok_or_else(|| format!("redirect_uri `{redirect_uri}` must include a loopback port"))?
+        .parse::<u16>()
+        .map_err(|_| format!("redirect_uri `{redirect_uri}` has an invalid port"))
+}
+
+fn authorize_url(client_id: &str, redirect_uri: &str, scopes: &str, challenge: &str) -> String {
+    format!(
+        "{AUTHORIZE_URL}?client_id={}&response_type=code&redirect_uri={}&code_challenge_method=S256&code_challenge={}&scope={}",
+        urlencoding::encode(client_id),
+        urlencoding::encode(redirect_uri),
+        urlencoding::encode(challenge),
+        urlencoding::encode(scopes),
+    )
+}
+
+/// A bound redirect listener and the PKCE state needed to finish the login.
+struct OAuthSession {
+    listener: TcpListener,
+    pkce: PkceChallenge,
+    client_id: String,
+    redirect_uri: String,
+}
+
+fn start_oauth_session(
+    client_id: String,
+    redirect_uri: String,
+    scopes: String,
+) -> Result<(String, OAuthSession), String> {
+    let pkce = generate_pkce();
+    let port = redirect_port(&redirect_uri)?;
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|err| format!("failed to bind OAuth redirect listener on port {port}: {err}"))?;
+    let url = authorize_url(&client_id, &redirect_uri, &scopes, &pkce.challenge);
+    Ok((
+        url,
+        OAuthSession {
+            listener,
+            pkce,
+            client_id,
+            redirect_uri,
+        },
+    ))
+}
+
+fn wait_for_redirect_code(listener: &TcpListener) -> Result<String, String> {
+    let (mut stream, _) = listener
+        .accept()
+        .map_err(|err| format!("failed to accept OAuth redirect connection: {err}"))?;
+    let mut reader = BufReader::new(
+        stream
+            .try_clone()
+            .map_err(|err| format!("failed to read OAuth redirect connection: {err}"))?,
+    );
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|err| format!("failed to read OAuth redirect request: {err}"))?;
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| "malformed OAuth redirect request".to_string())?;
+    let query = path.splitn(2, '?').nth(1).unwrap_or_default();
+    let code = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("code="))
+        .ok_or_else(|| "OAuth redirect did not include an authorization code".to_string())?;
+    let code = urlencoding::decode(code)
+        .map(|value| value.into_owned())
+        .unwrap_or_else(|_| code.to_string());
+
+    let body = "<html><body>Login complete. You may close this window.</body></html>";
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+    Ok(code)
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+fn exchange_code_for_credentials(
+    client_id: &str,
+    redirect_uri: &str,
+    code: &str,
+    code_verifier: &str,
+) -> Result<Credentials, String> {
+    let response: TokenResponse = ureq::post(TOKEN_URL)
+        .send_form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", client_id),
+            ("code_verifier", code_verifier),
+        ])
+        .map_err(|err| format!("OAuth token exchange failed: {err}"))?
+        .into_json()
+        .map_err(|err| format!("failed to parse OAuth token response: {err}"))?;
+    Ok(Credentials::with_access_token(response.access_token))
+}
+
+fn complete_oauth_session(session: OAuthSession) -> Result<Credentials, String> {
+    let code = wait_for_redirect_code(&session.listener)?;
+    exchange_code_for_credentials(
+        &session.client_id,
+        &session.redirect_uri,
+        &code,
+        &session.pkce.verifier,
+    )
+}
+
+fn read_oauth_args(
+    client_id: *const c_char,
+    redirect_uri: *const c_char,
+    scopes: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> Option<(String, String, String)> {
+    let client_id = read_cstr(client_id, "client_id", out_error)?;
+    let redirect_uri = read_cstr(redirect_uri, "redirect_uri", out_error)?;
+    let scopes = read_cstr(scopes, "scopes", out_error)?;
+    Some((client_id, redirect_uri, scopes))
+}
+
+/// Performs a blocking OAuth authorization-code (PKCE) login.
+///
+/// Binds a loopback listener on the port encoded in `redirect_uri`, prints the
+/// authorize URL to stderr, and blocks (on `runtime()`) until the browser completes
+/// the redirect and the access token exchange finishes. `scopes` is a
+/// space-separated list of Spotify scopes.
+///
+/// The returned handle must be released with `cspot_credentials_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_oauth_login(
+    client_id: *const c_char,
+    redirect_uri: *const c_char,
+    scopes: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_credentials_t {
+    clear_error(out_error);
+    let (client_id, redirect_uri, scopes) =
+        match read_oauth_args(client_id, redirect_uri, scopes, out_error) {
+            Some(value) => value,
+            None => return ptr::null_mut(),
+        };
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        runtime().block_on(async move {
+            tokio::task::spawn_blocking(move || {
+                let (url, session) = start_oauth_session(client_id, redirect_uri, scopes)?;
+                eprintln!("Open the following URL to finish logging in to Spotify:\n{url}");
+                complete_oauth_session(session)
+            })
+            .await
+            .unwrap_or_else(|err| Err(format!("OAuth login task panicked: {err}")))
+        })
+    }));
+
+    match result {
+        Ok(Ok(credentials)) => credentials_handle_from(credentials),
+        Ok(Err(err)) => {
+            write_error(out_error, err);
+            ptr::null_mut()
+        }
+        Err(_) => {
+            write_error(out_error, "panic during OAuth login");
+            ptr::null_mut()
+        }
+    }
+}
+
+struct OAuthPendingHandle {
+    task: JoinHandle<Result<Credentials, String>>,
+}
+
+/// Starts a non-blocking OAuth authorization-code (PKCE) login.
+///
+/// Binds the redirect listener immediately and returns the authorize URL through
+/// `out_url` (heap-allocated, freed with `cspot_string_free`) for the caller to open
+/// in a browser. Poll the returned handle with `cspot_oauth_poll`.
+///
+/// The returned handle must be released with `cspot_oauth_pending_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_oauth_begin(
+    client_id: *const c_char,
+    redirect_uri: *const c_char,
+    scopes: *const c_char,
+    out_url: *mut *mut c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_oauth_pending_t {
+    clear_error(out_error);
+    if out_url.is_null() {
+        write_error(out_error, "out_url was null");
+        return ptr::null_mut();
+    }
+    // Safety: out_url is non-null and points to writable memory.
+    unsafe {
+        *out_url = ptr::null_mut();
+    }
+
+    let (client_id, redirect_uri, scopes) =
+        match read_oauth_args(client_id, redirect_uri, scopes, out_error) {
+            Some(value) => value,
+            None => return ptr::null_mut(),
+        };
+
+    let (url, session) = match start_oauth_session(client_id, redirect_uri, scopes) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+
+    let task = runtime().spawn_blocking(move || complete_oauth_session(session));
+    // Safety: out_url is non-null and points to writable memory.
+    unsafe {
+        *out_url = cstring_from_str_lossy(&url).into_raw();
+    }
+    Box::into_raw(Box::new(OAuthPendingHandle { task })) as *mut cspot_oauth_pending_t
+}
+
+/// Polls a pending OAuth login without blocking.
+///
+/// On `CSPOT_OAUTH_POLL_READY`, `out_credentials` is populated with a handle that
+/// must be released with `cspot_credentials_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_oauth_poll(
+    pending: *mut cspot_oauth_pending_t,
+    out_credentials: *mut *mut cspot_credentials_t,
+    out_error: *mut *mut cspot_error_t,
+) -> cspot_oauth_poll_result_t {
+    clear_error(out_error);
+    if out_credentials.is_null() {
+        write_error(out_error, "out_credentials was null");
+        return cspot_oauth_poll_result_t::CSPOT_OAUTH_POLL_ERROR;
+    }
+    // Safety: out_credentials is non-null and points to writable memory.
+    unsafe {
+        *out_credentials = ptr::null_mut();
+    }
+    if pending.is_null() {
+        write_error(out_error, "pending handle was null");
+        return cspot_oauth_poll_result_t::CSPOT_OAUTH_POLL_ERROR;
+    }
+    // Safety: pending must be a valid handle allocated by cspot.
+    let handle = unsafe { &mut *(pending as *mut OAuthPendingHandle) };
+    if !handle.task.is_finished() {
+        return cspot_oauth_poll_result_t::CSPOT_OAUTH_POLL_PENDING;
+    }
+
+    match runtime().block_on(&mut handle.task) {
+        Ok(Ok(credentials)) => {
+            // Safety: out_credentials is non-null and points to writable memory.
+            unsafe {
+                *out_credentials = credentials_handle_from(credentials);
+            }
+            cspot_oauth_poll_result_t::CSPOT_OAUTH_POLL_READY
+        }
+        Ok(Err(err)) => {
+            write_error(out_error, err);
+            cspot_oauth_poll_result_t::CSPOT_OAUTH_POLL_ERROR
+        }
+        Err(err) => {
+            write_error(out_error, format!("OAuth login task panicked: {err}"));
+            cspot_oauth_poll_result_t::CSPOT_OAUTH_POLL_ERROR
+        }
+    }
+}
+
+/// Frees a pending OAuth login handle and aborts its login task.
+///
+/// The login runs as a blocking task, so a login that is already waiting for
+/// the browser redirect is not interrupted: its redirect listener stays bound
+/// until a connection arrives.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_oauth_pending_free(pending: *mut cspot_oauth_pending_t) {
+    if pending.is_null() {
+        return;
+    }
+    // Safety: pending must be a valid handle allocated by cspot.
+    let handle = unsafe { Box::from_raw(pending as *mut OAuthPendingHandle) };
+    handle.task.abort();
+}
@@ -1,19 +1,98 @@
 //! C bindings for librespot playback components.
 
+use std::os::raw::{c_char, c_void};
 use std::panic::AssertUnwindSafe;
 use std::ptr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use librespot::playback::{
-    audio_backend,
-    config::{AudioFormat, PlayerConfig},
+    audio_backend::{self, Sink, SinkError, SinkResult},
+    config::{AudioFormat, Bitrate, PlayerConfig},
+    convert::Converter,
+    decoder::AudioPacket,
     mixer::{self, Mixer, MixerConfig},
-    player::Player,
+    player::{Player, PlayerEvent},
 };
+use tokio::task::JoinHandle;
 
-use crate::error::{clear_error, cspot_error_t, write_error};
+use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
+use crate::ffi::read_cstr;
+use crate::runtime::runtime;
 use crate::session::session_from_handle;
 
+/// Playback bitrate, mapped to librespot's `Bitrate`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum cspot_bitrate_t {
+    CSPOT_BITRATE_96 = 0,
+    CSPOT_BITRATE_160 = 1,
+    CSPOT_BITRATE_320 = 2,
+}
+
+impl From<cspot_bitrate_t> for Bitrate {
+    fn from(value: cspot_bitrate_t) -> Self {
+        match value {
+            cspot_bitrate_t::CSPOT_BITRATE_96 => Bitrate::Bitrate96,
+            cspot_bitrate_t::CSPOT_BITRATE_160 => Bitrate::Bitrate160,
+            cspot_bitrate_t::CSPOT_BITRATE_320 => Bitrate::Bitrate320,
+        }
+    }
+}
+
+/// Decoded sample format, mapped to librespot's `AudioFormat`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum cspot_sample_format_t {
+    CSPOT_SAMPLE_FORMAT_F32 = 0,
+    CSPOT_SAMPLE_FORMAT_S16 = 1,
+}
+
+impl From<cspot_sample_format_t> for AudioFormat {
+    fn from(value: cspot_sample_format_t) -> Self {
+        match value {
+            cspot_sample_format_t::CSPOT_SAMPLE_FORMAT_F32 => AudioFormat::F32,
+            cspot_sample_format_t::CSPOT_SAMPLE_FORMAT_S16 => AudioFormat::S16,
+        }
+    }
+}
+
+/// Configuration for `cspot_player_create`.
+///
+/// `backend_name` may be null to use the default audio backend; otherwise it
+/// is resolved via librespot's `audio_backend::find`, and an unrecognized
+/// name is reported through `out_error`.
+#[repr(C)]
+pub struct cspot_player_config_t {
+    pub bitrate: cspot_bitrate_t,
+    pub normalisation: bool,
+    pub normalisation_pregain_db: f32,
+    pub gapless: bool,
+    pub sample_format: cspot_sample_format_t,
+    pub backend_name: *const c_char,
+}
+
+/// Initializes `cspot_player_config_t` with cspot's defaults: 320kbps,
+/// normalisation disabled, gapless enabled, `F32` samples, default backend.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_config_init(config: *mut cspot_player_config_t) {
+    if config.is_null() {
+        return;
+    }
+    // Safety: caller provided a writable config pointer.
+    unsafe {
+        *config = cspot_player_config_t {
+            bitrate: cspot_bitrate_t::CSPOT_BITRATE_320,
+            normalisation: false,
+            normalisation_pregain_db: 0.0,
+            gapless: true,
+            sample_format: cspot_sample_format_t::CSPOT_SAMPLE_FORMAT_F32,
+            backend_name: ptr::null(),
+        };
+    }
+}
+
 /// Opaque mixer handle for C callers.
 #[allow(non_camel_case_types)]
 pub struct cspot_mixer_t;
@@ -28,6 +107,283 @@ struct MixerHandle {
 
 struct PlayerHandle {
     player: Arc<Player>,
+    sink_state: Arc<SinkState>,
+    event_callback: Arc<Mutex<Option<PlaybackEventCallback>>>,
+    event_task: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// Kinds of events delivered through `cspot_player_register_event_callback`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum cspot_playback_event_kind_t {
+    CSPOT_PLAYBACK_EVENT_PLAYING = 0,
+    CSPOT_PLAYBACK_EVENT_PAUSED = 1,
+    CSPOT_PLAYBACK_EVENT_STOPPED = 2,
+    CSPOT_PLAYBACK_EVENT_TRACK_CHANGED = 3,
+    CSPOT_PLAYBACK_EVENT_END_OF_TRACK = 4,
+    CSPOT_PLAYBACK_EVENT_LOADING = 5,
+    CSPOT_PLAYBACK_EVENT_UNAVAILABLE = 6,
+    CSPOT_PLAYBACK_EVENT_VOLUME_CHANGED = 7,
+}
+
+/// Structured player event delivered to `cspot_player_register_event_callback`.
+///
+/// String pointers are only valid for the duration of the callback and must
+/// not be retained. Fields that don't apply to `kind` hold their default value.
+#[repr(C)]
+pub struct cspot_playback_event_t {
+    pub kind: cspot_playback_event_kind_t,
+    pub track_id: *const c_char,
+    pub position_ms: u32,
+    pub duration_ms: u32,
+    pub volume: u16,
+}
+
+/// Callback invoked for each decoded player event.
+///
+/// Invoked from a single cspot worker task on the shared Tokio runtime, which
+/// may run on any of the runtime's threads; it is never re-entered
+/// concurrently with itself.
+#[allow(non_camel_case_types)]
+pub type cspot_playback_event_callback_t =
+    Option<extern "C" fn(event: *const cspot_playback_event_t, user_data: *mut c_void)>;
+
+struct PlaybackEventCallback {
+    callback: cspot_playback_event_callback_t,
+    user_data: usize,
+}
+
+struct PlaybackEventNotice {
+    kind: cspot_playback_event_kind_t,
+    track_id: Option<String>,
+    position_ms: u32,
+    duration_ms: u32,
+    volume: u16,
+}
+
+fn playback_event_notice(event: PlayerEvent) -> Option<PlaybackEventNotice> {
+    match event {
+        PlayerEvent::Playing {
+            track_id,
+            position_ms,
+            duration_ms,
+            ..
+        } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_PLAYING,
+            track_id: Some(track_id.to_string()),
+            position_ms,
+            duration_ms,
+            volume: 0,
+        }),
+        PlayerEvent::Paused {
+            track_id,
+            position_ms,
+            duration_ms,
+            ..
+        } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_PAUSED,
+            track_id: Some(track_id.to_string()),
+            position_ms,
+            duration_ms,
+            volume: 0,
+        }),
+        PlayerEvent::Stopped { track_id, .. } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_STOPPED,
+            track_id: Some(track_id.to_string()),
+            position_ms: 0,
+            duration_ms: 0,
+            volume: 0,
+        }),
+        PlayerEvent::TrackChanged { audio_item } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_TRACK_CHANGED,
+            track_id: Some(audio_item.track_id.to_string()),
+            position_ms: 0,
+            duration_ms: audio_item.duration_ms,
+            volume: 0,
+        }),
+        PlayerEvent::EndOfTrack { track_id, .. } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_END_OF_TRACK,
+            track_id: Some(track_id.to_string()),
+            position_ms: 0,
+            duration_ms: 0,
+            volume: 0,
+        }),
+        PlayerEvent::Loading {
+            track_id,
+            position_ms,
+            ..
+        } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_LOADING,
+            track_id: Some(track_id.to_string()),
+            position_ms,
+            duration_ms: 0,
+            volume: 0,
+        }),
+        PlayerEvent::Unavailable { track_id, .. } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_UNAVAILABLE,
+            track_id: Some(track_id.to_string()),
+            position_ms: 0,
+            duration_ms: 0,
+            volume: 0,
+        }),
+        PlayerEvent::VolumeChanged { volume } => Some(PlaybackEventNotice {
+            kind: cspot_playback_event_kind_t::CSPOT_PLAYBACK_EVENT_VOLUME_CHANGED,
+            track_id: None,
+            position_ms: 0,
+            duration_ms: 0,
+            volume,
+        }),
+        _ => None,
+    }
+}
+
+fn invoke_playback_event_callback(
+    event_callback: &Mutex<Option<PlaybackEventCallback>>,
+    notice: PlaybackEventNotice,
+) {
+    let (callback, user_data) = {
+        let guard = event_callback.lock().unwrap_or_else(|err| err.into_inner());
+        match guard.as_ref() {
+            Some(state) => (state.callback, state.user_data as *mut c_void),
+            None => return,
+        }
+    };
+    let Some(callback) = callback else {
+        return;
+    };
+
+    let track_id = notice.track_id.as_deref().map(cstring_from_str_lossy);
+    let event = cspot_playback_event_t {
+        kind: notice.kind,
+        track_id: track_id.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        position_ms: notice.position_ms,
+        duration_ms: notice.duration_ms,
+        volume: notice.volume,
+    };
+    if std::panic::catch_unwind(AssertUnwindSafe(|| callback(&event, user_data))).is_err() {
+        eprintln!("cspot: panic in player event callback");
+    }
+}
+
+/// Selects whether decoded audio is routed to the local audio backend or
+/// handed to the callback registered with `cspot_player_set_pcm_callback`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum cspot_sink_mode_t {
+    CSPOT_SINK_INTERNAL = 0,
+    CSPOT_SINK_CALLBACK = 1,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SinkMode {
+    Internal,
+    Callback,
+}
+
+impl From<cspot_sink_mode_t> for SinkMode {
+    fn from(value: cspot_sink_mode_t) -> Self {
+        match value {
+            cspot_sink_mode_t::CSPOT_SINK_INTERNAL => Self::Internal,
+            cspot_sink_mode_t::CSPOT_SINK_CALLBACK => Self::Callback,
+        }
+    }
+}
+
+impl From<SinkMode> for cspot_sink_mode_t {
+    fn from(value: SinkMode) -> Self {
+        match value {
+            SinkMode::Internal => Self::CSPOT_SINK_INTERNAL,
+            SinkMode::Callback => Self::CSPOT_SINK_CALLBACK,
+        }
+    }
+}
+
+/// Receives decoded audio frames as normalized, interleaved `f32` samples
+/// while `cspot_player_set_sink_mode` is set to `CSPOT_SINK_CALLBACK`.
+#[allow(non_camel_case_types)]
+pub type cspot_pcm_callback_t = Option<
+    extern "C" fn(
+        user_data: *mut c_void,
+        samples: *const f32,
+        frame_count: usize,
+        sample_rate: u32,
+        channels: u32,
+    ),
+>;
+
+/// A `*mut c_void` that is only ever touched from behind the player's
+/// dedicated audio thread, so it is safe to send across the async boundary.
+struct SendUserData(*mut c_void);
+// Safety: the pointer is only dereferenced by the C callback the caller
+// supplied, which is documented as safe to invoke from any thread.
+unsafe impl Send for SendUserData {}
+
+/// Shared, runtime-mutable state backing the sink created for a player, so
+/// `cspot_player_set_pcm_callback`/`cspot_player_set_sink_mode` can change
+/// behavior without tearing down and recreating the `Player`.
+struct SinkState {
+    mode: Mutex<SinkMode>,
+    callback: Mutex<Option<(cspot_pcm_callback_t, SendUserData)>>,
+}
+
+impl Default for SinkState {
+    fn default() -> Self {
+        Self {
+            mode: Mutex::new(SinkMode::Internal),
+            callback: Mutex::new(None),
+        }
+    }
+}
+
+/// Wraps the real audio backend sink, tapping decoded frames out to a
+/// caller-supplied callback when `CSPOT_SINK_CALLBACK` mode is active.
+///
+/// librespot's `Sink` is fixed to a single backend for the lifetime of a
+/// `Player`, so toggling the mode at runtime is implemented here rather
+/// than by swapping sinks out from under the player.
+struct TappableSink {
+    backend: Box<dyn Sink>,
+    state: Arc<SinkState>,
+}
+
+impl Sink for TappableSink {
+    fn start(&mut self) -> SinkResult<()> {
+        self.backend.start()
+    }
+
+    fn stop(&mut self) -> SinkResult<()> {
+        self.backend.stop()
+    }
+
+    fn write(&mut self, packet: AudioPacket, converter: &mut Converter) -> SinkResult<()> {
+        let mode = *self.state.mode.lock().unwrap_or_else(|err| err.into_inner());
+        if mode != SinkMode::Callback {
+            return self.backend.write(packet, converter);
+        }
+
+        let samples = packet
+            .samples()
+            .map_err(|err| SinkError::OnWrite(err.to_string()))?;
+        let guard = self
+            .state
+            .callback
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        if let Some((Some(callback), user_data)) = guard.as_ref() {
+            let pcm = converter.f64_to_f32(samples);
+            let channels = 2;
+            callback(
+                user_data.0,
+                pcm.as_ptr(),
+                pcm.len() / channels,
+                44_100,
+                channels as u32,
+            );
+        }
+        Ok(())
+    }
 }
 
 /// Creates a mixer using the default mixer backend and default configuration.
@@ -56,13 +412,17 @@ pub extern "C" fn cspot_mixer_create_default(
     }
 }
 
-/// Creates a player using default configuration and the default audio backend.
+/// Creates a player using the given configuration.
 ///
-/// The returned handle must be released with `cspot_player_free`.
+/// `config` may be null to use librespot's `PlayerConfig::default()` and
+/// `AudioFormat::default()` with the default audio backend; these are not
+/// the values `cspot_player_config_init` fills in. The returned handle must
+/// be released with `cspot_player_free`.
 #[unsafe(no_mangle)]
-pub extern "C" fn cspot_player_create_default(
+pub extern "C" fn cspot_player_create(
     session: *const crate::session::cspot_session_t,
     mixer: *const cspot_mixer_t,
+    config: *const cspot_player_config_t,
     out_error: *mut *mut cspot_error_t,
 ) -> *mut cspot_player_t {
     clear_error(out_error);
@@ -82,19 +442,56 @@ pub extern "C" fn cspot_player_create_default(
     let mixer_handle = unsafe { &*(mixer as *const MixerHandle) };
     let mixer = Arc::clone(&mixer_handle.mixer);
 
+    let backend_name = match unsafe { config.as_ref() } {
+        Some(config) if !config.backend_name.is_null() => {
+            match read_cstr(config.backend_name, "backend_name", out_error) {
+                Some(value) => Some(value),
+                None => return ptr::null_mut(),
+            }
+        }
+        _ => None,
+    };
+
+    let player_config = match unsafe { config.as_ref() } {
+        Some(config) => PlayerConfig {
+            bitrate: config.bitrate.into(),
+            normalisation: config.normalisation,
+            normalisation_pregain: config.normalisation_pregain_db as f64,
+            gapless: config.gapless,
+            ..PlayerConfig::default()
+        },
+        None => PlayerConfig::default(),
+    };
+    let audio_format = unsafe { config.as_ref() }
+        .map(|config| config.sample_format.into())
+        .unwrap_or_default();
+
+    let sink_state = Arc::new(SinkState::default());
+    let tap_state = Arc::clone(&sink_state);
+
     let result = std::panic::catch_unwind(AssertUnwindSafe(|| -> Result<Arc<Player>, String> {
-        let backend = audio_backend::find(None)
-            .ok_or_else(|| "no audio backend available".to_string())?;
-        let player_config = PlayerConfig::default();
-        let audio_format = AudioFormat::default();
+        let backend = audio_backend::find(backend_name.as_deref()).ok_or_else(|| match &backend_name {
+            Some(name) => format!("unknown audio backend `{name}`"),
+            None => "no audio backend available".to_string(),
+        })?;
         let soft_volume = mixer.get_soft_volume();
         Ok(Player::new(player_config, session, soft_volume, move || {
-            backend(None, audio_format)
+            Box::new(TappableSink {
+                backend: backend(None, audio_format),
+                state: Arc::clone(&tap_state),
+            })
         }))
     }));
 
     match result {
-        Ok(Ok(player)) => Box::into_raw(Box::new(PlayerHandle { player })) as *mut cspot_player_t,
+        Ok(Ok(player)) => {
+            Box::into_raw(Box::new(PlayerHandle {
+                player,
+                sink_state,
+                event_callback: Arc::new(Mutex::new(None)),
+                event_task: Mutex::new(None),
+            })) as *mut cspot_player_t
+        }
         Ok(Err(err)) => {
             write_error(out_error, err);
             ptr::null_mut()
@@ -106,6 +503,151 @@ pub extern "C" fn cspot_player_create_default(
     }
 }
 
+/// Creates a player using default configuration and the default audio backend.
+///
+/// Thin wrapper over `cspot_player_create` with a null config. The returned
+/// handle must be released with `cspot_player_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_create_default(
+    session: *const crate::session::cspot_session_t,
+    mixer: *const cspot_mixer_t,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_player_t {
+    cspot_player_create(session, mixer, ptr::null(), out_error)
+}
+
+/// Registers a callback to receive decoded audio frames directly instead of
+/// the built-in audio backend; frames handed to the callback are not also
+/// written to the backend.
+///
+/// Samples are normalized, interleaved `f32`, reported alongside the sample
+/// rate and channel count. The callback only fires while the sink mode is
+/// `CSPOT_SINK_CALLBACK`; see `cspot_player_set_sink_mode`. Pass `None` for
+/// `callback` to stop receiving frames. Returns `false` if `player` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_set_pcm_callback(
+    player: *const cspot_player_t,
+    callback: cspot_pcm_callback_t,
+    user_data: *mut c_void,
+) -> bool {
+    if player.is_null() {
+        return false;
+    }
+    // Safety: player must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(player as *const PlayerHandle) };
+    let mut guard = handle
+        .sink_state
+        .callback
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    *guard = callback.map(|callback| (Some(callback), SendUserData(user_data)));
+    true
+}
+
+/// Selects whether decoded audio goes to the internal audio backend or to
+/// the callback registered with `cspot_player_set_pcm_callback`.
+///
+/// Defaults to `CSPOT_SINK_INTERNAL`. Returns `false` if `player` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_set_sink_mode(
+    player: *const cspot_player_t,
+    mode: cspot_sink_mode_t,
+) -> bool {
+    if player.is_null() {
+        return false;
+    }
+    // Safety: player must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(player as *const PlayerHandle) };
+    *handle
+        .sink_state
+        .mode
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = mode.into();
+    true
+}
+
+/// Returns the currently configured sink mode.
+///
+/// Returns `CSPOT_SINK_INTERNAL` if `player` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_sink_mode(player: *const cspot_player_t) -> cspot_sink_mode_t {
+    if player.is_null() {
+        return cspot_sink_mode_t::CSPOT_SINK_INTERNAL;
+    }
+    // Safety: player must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(player as *const PlayerHandle) };
+    let mode = *handle
+        .sink_state
+        .mode
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    mode.into()
+}
+
+/// Registers a callback to receive player events (play/pause/stop, track
+/// changes, end-of-track, loading, unavailable tracks, and volume changes).
+///
+/// Replaces any previously registered callback. The callback is invoked from
+/// a dedicated cspot worker task spawned on the shared runtime, never
+/// concurrently with itself. Returns `false` if `player` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_register_event_callback(
+    player: *const cspot_player_t,
+    callback: cspot_playback_event_callback_t,
+    user_data: *mut c_void,
+) -> bool {
+    if player.is_null() {
+        return false;
+    }
+    // Safety: player must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(player as *const PlayerHandle) };
+    {
+        let mut guard = handle
+            .event_callback
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        *guard = Some(PlaybackEventCallback {
+            callback,
+            user_data: user_data as usize,
+        });
+    }
+
+    let mut task_guard = handle
+        .event_task
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if task_guard.is_none() {
+        let mut event_channel = handle.player.get_player_event_channel();
+        let event_callback = Arc::clone(&handle.event_callback);
+        *task_guard = Some(runtime().spawn(async move {
+            while let Some(event) = event_channel.recv().await {
+                if let Some(notice) = playback_event_notice(event) {
+                    invoke_playback_event_callback(&event_callback, notice);
+                }
+            }
+        }));
+    }
+    true
+}
+
+/// Unregisters the player event callback set by
+/// `cspot_player_register_event_callback`.
+///
+/// Returns `false` if `player` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_unregister_event_callback(player: *const cspot_player_t) -> bool {
+    if player.is_null() {
+        return false;
+    }
+    // Safety: player must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(player as *const PlayerHandle) };
+    *handle
+        .event_callback
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = None;
+    true
+}
+
 /// Frees a mixer handle.
 #[unsafe(no_mangle)]
 pub extern "C" fn cspot_mixer_free(mixer: *mut cspot_mixer_t) {
@@ -125,8 +667,9 @@ pub extern "C" fn cspot_player_free(player: *mut cspot_player_t) {
         return;
     }
     // Safety: player must be a valid handle allocated by cspot.
-    unsafe {
-        drop(Box::from_raw(player as *mut PlayerHandle));
+    let handle = unsafe { Box::from_raw(player as *mut PlayerHandle) };
+    if let Some(task) = handle.event_task.lock().unwrap_or_else(|err| err.into_inner()).take() {
+        task.abort();
     }
 }
 
@@ -0,0 +1,468 @@
+//! High-level "player subsystem" bindings.
+//!
+//! Bundles session authentication, playback, and Spirc remote control behind a
+//! single handle so embedded C integrators can get audio flowing and react to
+//! remote-control events without wiring up `session`/`playback`/`connect` by hand.
+
+use std::os::raw::{c_char, c_void};
+use std::panic::AssertUnwindSafe;
+use std::ptr;
+use std::sync::Arc;
+
+use librespot::connect::{ConnectConfig, LoadRequest, LoadRequestOptions, Spirc};
+use librespot::core::{Session, SessionConfig};
+use librespot::metadata::audio::UniqueFields;
+use librespot::playback::{
+    audio_backend::{Sink, SinkError, SinkResult},
+    config::{AudioFormat, PlayerConfig},
+    convert::Converter,
+    decoder::AudioPacket,
+    mixer::{self, MixerConfig},
+    player::{Player, PlayerEvent},
+};
+
+use crate::discovery::{credentials_from_handle, cspot_credentials_t, cspot_device_type_t};
+use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
+use crate::ffi::read_cstr;
+use crate::runtime::runtime;
+
+/// Opaque player-session handle for C callers.
+#[allow(non_camel_case_types)]
+pub struct cspot_player_session_t;
+
+/// Kinds of events delivered through `cspot_player_session_config_t::event_callback`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub enum cspot_player_session_event_t {
+    CSPOT_PLAYER_SESSION_EVENT_PLAY = 0,
+    CSPOT_PLAYER_SESSION_EVENT_PAUSE = 1,
+    CSPOT_PLAYER_SESSION_EVENT_SEEK = 2,
+    CSPOT_PLAYER_SESSION_EVENT_VOLUME = 3,
+    CSPOT_PLAYER_SESSION_EVENT_TRACK_CHANGED = 4,
+    CSPOT_PLAYER_SESSION_EVENT_DISCONNECT = 5,
+}
+
+/// Track metadata accompanying a player-session event.
+///
+/// String fields are null when unknown. Pointers are only valid for the duration
+/// of the callback and must not be retained.
+#[repr(C)]
+pub struct cspot_track_info_t {
+    pub uri: *const c_char,
+    pub title: *const c_char,
+    pub artist: *const c_char,
+    pub album: *const c_char,
+    pub duration_ms: u32,
+    pub position_ms: u32,
+    pub volume: u16,
+}
+
+/// Data callback receiving decoded PCM frames (interleaved 16-bit signed samples).
+#[allow(non_camel_case_types)]
+pub type cspot_player_session_data_callback_t = Option<
+    extern "C" fn(
+        user_data: *mut c_void,
+        pcm: *const u8,
+        bytes: usize,
+        sample_rate: u32,
+        channels: u8,
+    ),
+>;
+
+/// Event callback receiving remote-control and playback-state transitions.
+#[allow(non_camel_case_types)]
+pub type cspot_player_session_event_callback_t = Option<
+    extern "C" fn(
+        user_data: *mut c_void,
+        event: cspot_player_session_event_t,
+        info: *const cspot_track_info_t,
+    ),
+>;
+
+/// Configuration for `cspot_player_session_create`.
+///
+/// Either callback may be null to opt out of that stream.
+#[repr(C)]
+pub struct cspot_player_session_config_t {
+    pub data_callback: cspot_player_session_data_callback_t,
+    pub event_callback: cspot_player_session_event_callback_t,
+    pub user_data: *mut c_void,
+}
+
+/// A `*mut c_void` that is only ever handed back to the caller's C callbacks
+/// (from the player's audio sink and from the event task), so it is safe to
+/// send across the async boundary.
+struct SendUserData(*mut c_void);
+// Safety: the pointer is only dereferenced by the C callbacks the caller supplied,
+// which are documented as safe to invoke from any thread.
+unsafe impl Send for SendUserData {}
+
+struct PcmCallbackSink {
+    callback: cspot_player_session_data_callback_t,
+    user_data: SendUserData,
+}
+
+impl Sink for PcmCallbackSink {
+    fn write(&mut self, packet: AudioPacket, converter: &mut Converter) -> SinkResult<()> {
+        let samples = packet
+            .samples()
+            .map_err(|err| SinkError::OnWrite(err.to_string()))?;
+        if let Some(callback) = self.callback {
+            let pcm = converter.f64_to_s16(samples);
+            let bytes = pcm.len() * std::mem::size_of::<i16>();
+            callback(
+                self.user_data.0,
+                pcm.as_ptr() as *const u8,
+                bytes,
+                44_100,
+                2,
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct TrackInfo {
+    uri: Option<String>,
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    duration_ms: u32,
+    position_ms: u32,
+}
+
+fn invoke_event_callback(
+    callback: cspot_player_session_event_callback_t,
+    user_data: *mut c_void,
+    event: cspot_player_session_event_t,
+    track: &TrackInfo,
+    volume: u16,
+) {
+    let Some(callback) = callback else {
+        return;
+    };
+    let uri = track.uri.as_deref().map(cstring_from_str_lossy);
+    let title = track.title.as_deref().map(cstring_from_str_lossy);
+    let artist = track.artist.as_deref().map(cstring_from_str_lossy);
+    let album = track.album.as_deref().map(cstring_from_str_lossy);
+    let info = cspot_track_info_t {
+        uri: uri.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        title: title.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        artist: artist.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        album: album.as_ref().map_or(ptr::null(), |value| value.as_ptr()),
+        duration_ms: track.duration_ms,
+        position_ms: track.position_ms,
+        volume,
+    };
+    if std::panic::catch_unwind(AssertUnwindSafe(|| callback(user_data, event, &info))).is_err() {
+        eprintln!("cspot: panic in player-session event callback");
+    }
+}
+
+struct PlayerSessionHandle {
+    spirc: Spirc,
+    status_task: tokio::task::JoinHandle<()>,
+    spirc_task: tokio::task::JoinHandle<()>,
+}
+
+fn run_spirc_event_loop(
+    player: Arc<Player>,
+    callback: cspot_player_session_event_callback_t,
+    user_data: SendUserData,
+) -> tokio::task::JoinHandle<()> {
+    let mut events = player.get_player_event_channel();
+    runtime().spawn(async move {
+        let user_data = user_data;
+        let mut track = TrackInfo::default();
+        let mut volume: u16 = 0;
+        while let Some(event) = events.recv().await {
+            let emitted = match event {
+                PlayerEvent::Playing { position_ms, .. } => {
+                    track.position_ms = position_ms;
+                    Some(cspot_player_session_event_t::CSPOT_PLAYER_SESSION_EVENT_PLAY)
+                }
+                PlayerEvent::Paused { position_ms, .. } => {
+                    track.position_ms = position_ms;
+                    Some(cspot_player_session_event_t::CSPOT_PLAYER_SESSION_EVENT_PAUSE)
+                }
+                PlayerEvent::Seeked { position_ms, .. } => {
+                    track.position_ms = position_ms;
+                    Some(cspot_player_session_event_t::CSPOT_PLAYER_SESSION_EVENT_SEEK)
+                }
+                PlayerEvent::VolumeChanged { volume: new_volume } => {
+                    volume = new_volume;
+                    Some(cspot_player_session_event_t::CSPOT_PLAYER_SESSION_EVENT_VOLUME)
+                }
+                PlayerEvent::TrackChanged { audio_item } => {
+                    track.uri = Some(audio_item.uri.clone());
+                    track.title = Some(audio_item.name.clone());
+                    track.duration_ms = audio_item.duration_ms;
+                    (track.artist, track.album) = match &audio_item.unique_fields {
+                        UniqueFields::Track { artists, album, .. } => {
+                            let names: Vec<&str> =
+                                artists.iter().map(|artist| artist.name.as_str()).collect();
+                            ((!names.is_empty()).then(|| names.join(", ")), Some(album.clone()))
+                        }
+                        UniqueFields::Local { artists, album, .. } => {
+                            (artists.clone(), album.clone())
+                        }
+                        UniqueFields::Episode { show_name, .. } => (None, Some(show_name.clone())),
+                    };
+                    Some(cspot_player_session_event_t::CSPOT_PLAYER_SESSION_EVENT_TRACK_CHANGED)
+                }
+                PlayerEvent::SessionDisconnected { .. } => {
+                    Some(cspot_player_session_event_t::CSPOT_PLAYER_SESSION_EVENT_DISCONNECT)
+                }
+                _ => None,
+            };
+            if let Some(event) = emitted {
+                invoke_event_callback(callback, user_data.0, event, &track, volume);
+            }
+        }
+    })
+}
+
+/// Creates a player session: authenticates a session from `credentials`, starts
+/// playback and a Spirc connect handler, and wires up the configured callbacks.
+///
+/// All work is marshalled onto `runtime()`. The returned handle must be released
+/// with `cspot_player_session_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_create(
+    credentials: *const cspot_credentials_t,
+    device_id: *const c_char,
+    client_id: *const c_char,
+    name: *const c_char,
+    device_type: cspot_device_type_t,
+    config: *const cspot_player_session_config_t,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_player_session_t {
+    clear_error(out_error);
+    let credentials = match credentials_from_handle(credentials) {
+        Some(value) => value,
+        None => {
+            write_error(out_error, "credentials handle was null");
+            return ptr::null_mut();
+        }
+    };
+    let device_id = match read_cstr(device_id, "device_id", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let client_id = match read_cstr(client_id, "client_id", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let name = match read_cstr(name, "name", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    // Safety: config, if non-null, must be a valid cspot_player_session_config_t.
+    let config = unsafe { config.as_ref() };
+    let data_callback = config.and_then(|config| config.data_callback);
+    let event_callback = config.and_then(|config| config.event_callback);
+    let user_data = config.map(|config| config.user_data).unwrap_or(ptr::null_mut());
+
+    let mut session_config = SessionConfig::default();
+    session_config.device_id = device_id;
+    session_config.client_id = client_id;
+
+    let connect_config = ConnectConfig {
+        name,
+        device_type: device_type.into(),
+        ..ConnectConfig::default()
+    };
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        runtime().block_on(async move {
+            let session = Session::new(session_config, None);
+            session
+                .connect(credentials.clone(), false)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            let mixer = mixer::find(None)
+                .ok_or_else(|| "no mixer backend available".to_string())?(
+                MixerConfig::default(),
+            )
+            .map_err(|err| err.to_string())?;
+            let soft_volume = mixer.get_soft_volume();
+
+            let sink_data_callback = data_callback;
+            let sink_user_data = SendUserData(user_data);
+            let player = Player::new(
+                PlayerConfig::default(),
+                session.clone(),
+                soft_volume,
+                move || {
+                    Box::new(PcmCallbackSink {
+                        callback: sink_data_callback,
+                        user_data: sink_user_data,
+                    })
+                },
+            );
+
+            let status_task = run_spirc_event_loop(
+                Arc::clone(&player),
+                event_callback,
+                SendUserData(user_data),
+            );
+
+            let (spirc, spirc_task) = Spirc::new(connect_config, session, credentials, player, mixer)
+                .await
+                .map_err(|err| err.to_string())?;
+            let spirc_task = runtime().spawn(spirc_task);
+            Ok::<_, String>(PlayerSessionHandle {
+                spirc,
+                status_task,
+                spirc_task,
+            })
+        })
+    }));
+
+    match result {
+        Ok(Ok(handle)) => Box::into_raw(Box::new(handle)) as *mut cspot_player_session_t,
+        Ok(Err(err)) => {
+            write_error(out_error, err);
+            ptr::null_mut()
+        }
+        Err(_) => {
+            write_error(out_error, "panic while creating player session");
+            ptr::null_mut()
+        }
+    }
+}
+
+fn with_session<T>(
+    session: *const cspot_player_session_t,
+    out_error: *mut *mut cspot_error_t,
+    f: impl FnOnce(&PlayerSessionHandle) -> Result<T, String>,
+) -> Option<T> {
+    clear_error(out_error);
+    if session.is_null() {
+        write_error(out_error, "player session handle was null");
+        return None;
+    }
+    // Safety: session must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(session as *const PlayerSessionHandle) };
+    match f(handle) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            write_error(out_error, err);
+            None
+        }
+    }
+}
+
+/// Loads a single Spotify track URI for playback on this player session.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_load(
+    session: *const cspot_player_session_t,
+    track_uri: *const c_char,
+    start_playing: bool,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    clear_error(out_error);
+    let track_uri = match read_cstr(track_uri, "track_uri", out_error) {
+        Some(value) => value,
+        None => return false,
+    };
+    let mut options = LoadRequestOptions::default();
+    options.start_playing = start_playing;
+    let request = LoadRequest::from_tracks(vec![track_uri], options);
+    with_session(session, out_error, |handle| {
+        handle.spirc.load(request).map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Resumes/starts playback.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_play(
+    session: *const cspot_player_session_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    with_session(session, out_error, |handle| {
+        handle.spirc.play().map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Pauses playback.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_pause(
+    session: *const cspot_player_session_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    with_session(session, out_error, |handle| {
+        handle.spirc.pause().map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Seeks within the current track in milliseconds.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_seek_ms(
+    session: *const cspot_player_session_t,
+    position_ms: u32,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    with_session(session, out_error, |handle| {
+        handle
+            .spirc
+            .set_position_ms(position_ms)
+            .map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Sets the absolute playback volume.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_set_volume(
+    session: *const cspot_player_session_t,
+    volume: u16,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    with_session(session, out_error, |handle| {
+        handle.spirc.set_volume(volume).map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Skips to the next track.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_next(
+    session: *const cspot_player_session_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    with_session(session, out_error, |handle| {
+        handle.spirc.next().map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Returns to the previous track.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_prev(
+    session: *const cspot_player_session_t,
+    out_error: *mut *mut cspot_error_t,
+) -> bool {
+    with_session(session, out_error, |handle| {
+        handle.spirc.prev().map_err(|err| err.to_string())
+    })
+    .is_some()
+}
+
+/// Frees a player session, tearing down its background event worker and
+/// Spirc task.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_player_session_free(session: *mut cspot_player_session_t) {
+    if session.is_null() {
+        return;
+    }
+    // Safety: session must be a valid handle allocated by cspot.
+    let handle = unsafe { Box::from_raw(session as *mut PlayerSessionHandle) };
+    handle.status_task.abort();
+    handle.spirc_task.abort();
+}
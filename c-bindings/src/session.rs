@@ -5,8 +5,10 @@ use std::os::raw::c_char;
 use std::panic::AssertUnwindSafe;
 use std::ptr;
 
+use librespot::core::cache::Cache;
 use librespot::core::{config::SessionConfig, session::Session};
 
+use crate::discovery::{credentials_from_handle, cspot_credentials_t};
 use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
 use crate::ffi::read_cstr;
 use crate::runtime::runtime;
@@ -19,6 +21,48 @@ struct SessionHandle {
     session: Session,
 }
 
+/// On-disk cache locations for `cspot_session_connect`.
+///
+/// Any path may be null to opt that cache out. `max_size_bytes` caps the
+/// audio cache; pass 0 for no limit.
+#[repr(C)]
+pub struct cspot_cache_config_t {
+    pub credentials_path: *const c_char,
+    pub volume_path: *const c_char,
+    pub audio_path: *const c_char,
+    pub max_size_bytes: u64,
+}
+
+/// Reads an optional C string field: null means "not set", not an error.
+fn read_optional_cstr(value: *const c_char) -> Option<String> {
+    if value.is_null() {
+        return None;
+    }
+    // Safety: caller guarantees a valid, NUL-terminated C string.
+    let cstr = unsafe { std::ffi::CStr::from_ptr(value) };
+    Some(cstr.to_string_lossy().into_owned())
+}
+
+fn cache_from_config(config: *const cspot_cache_config_t) -> Result<Option<Cache>, String> {
+    if config.is_null() {
+        return Ok(None);
+    }
+    // Safety: config must be a valid pointer to a cspot_cache_config_t.
+    let config = unsafe { &*config };
+    let credentials_path = read_optional_cstr(config.credentials_path);
+    let volume_path = read_optional_cstr(config.volume_path);
+    let audio_path = read_optional_cstr(config.audio_path);
+    let max_size = if config.max_size_bytes == 0 {
+        None
+    } else {
+        Some(config.max_size_bytes)
+    };
+
+    Cache::new(credentials_path, volume_path, audio_path, max_size)
+        .map(Some)
+        .map_err(|err| err.to_string())
+}
+
 /// Creates a new session using the provided device id.
 ///
 /// The returned handle must be released with `cspot_session_free`.
@@ -50,6 +94,65 @@ pub extern "C" fn cspot_session_create(
     }
 }
 
+/// Creates a new session and authenticates it with the provided credentials.
+///
+/// `cache_config` may be null to run without any on-disk cache. On success,
+/// the resulting credentials are cached for reuse by
+/// `cspot_credentials_with_stored` if `cache_config.credentials_path` is set.
+/// The returned handle must be released with `cspot_session_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_session_connect(
+    device_id: *const c_char,
+    credentials: *const cspot_credentials_t,
+    cache_config: *const cspot_cache_config_t,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_session_t {
+    clear_error(out_error);
+    let device_id = match read_cstr(device_id, "device_id", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let credentials = match credentials_from_handle(credentials) {
+        Some(value) => value,
+        None => {
+            write_error(out_error, "credentials handle was null");
+            return ptr::null_mut();
+        }
+    };
+    let cache = match cache_from_config(cache_config) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+    let store_credentials = cache.is_some();
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        runtime().block_on(async {
+            let mut config = SessionConfig::default();
+            config.device_id = device_id;
+            let session = Session::new(config, cache);
+            session
+                .connect(credentials, store_credentials)
+                .await
+                .map(|_| session)
+        })
+    }));
+
+    match result {
+        Ok(Ok(session)) => Box::into_raw(Box::new(SessionHandle { session })) as *mut cspot_session_t,
+        Ok(Err(err)) => {
+            write_error(out_error, err.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            write_error(out_error, "panic while connecting session");
+            ptr::null_mut()
+        }
+    }
+}
+
 /// Returns the session username, or null if unavailable.
 ///
 /// The returned string is heap-allocated and must be freed with `cspot_string_free`.
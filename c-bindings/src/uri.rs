@@ -1,13 +1,18 @@
-//! C bindings for Spotify URI helpers.
+//! C bindings for Spotify URI helpers and metadata lookups.
 
 use std::ffi::CString;
 use std::os::raw::c_char;
+use std::panic::AssertUnwindSafe;
 use std::ptr;
 
 use librespot::core::{spotify_id::SpotifyId, spotify_uri::SpotifyUri};
+use librespot::metadata::audio::{AudioItem, UniqueFields};
+use librespot::metadata::{Album, Artist, Metadata};
 
 use crate::error::{clear_error, cspot_error_t, cstring_from_str_lossy, write_error};
 use crate::ffi::read_cstr;
+use crate::runtime::runtime;
+use crate::session::{cspot_session_t, session_from_handle};
 
 /// Builds a Spotify track URI from either a track URI or base62 track id.
 ///
@@ -54,3 +59,365 @@ pub extern "C" fn cspot_track_uri_from_input(
         }
     }
 }
+
+fn parse_spotify_id(input: &str) -> Result<SpotifyId, String> {
+    if let Ok(uri) = SpotifyUri::from_uri(input) {
+        return match uri {
+            SpotifyUri::Track { id }
+            | SpotifyUri::Episode { id }
+            | SpotifyUri::Album { id }
+            | SpotifyUri::Artist { id } => Ok(id),
+            _ => Err(format!("`{input}` is not a track, album, or artist URI")),
+        };
+    }
+    SpotifyId::from_base62(input)
+        .map_err(|_| format!("`{input}` is not a valid Spotify URI or base62 id"))
+}
+
+/// Opaque track metadata handle for C callers.
+#[allow(non_camel_case_types)]
+pub struct cspot_track_t;
+
+struct TrackHandle {
+    name: CString,
+    duration_ms: u32,
+    artists: Vec<CString>,
+    album_name: Option<CString>,
+    cover_url: Option<CString>,
+}
+
+/// Fetches metadata for a track or episode.
+///
+/// `uri` accepts a `spotify:track:...`/`spotify:episode:...` URI or a base62 id.
+/// Runs on `runtime()`. The returned handle must be released with `cspot_track_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_metadata_track_fetch(
+    session: *const cspot_session_t,
+    uri: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_track_t {
+    clear_error(out_error);
+    let session = match session_from_handle(session) {
+        Some(value) => value,
+        None => {
+            write_error(out_error, "session handle was null");
+            return ptr::null_mut();
+        }
+    };
+    let uri = match read_cstr(uri, "uri", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let id = match parse_spotify_id(&uri) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        runtime().block_on(async { AudioItem::get_file(&session, id).await })
+    }));
+
+    match result {
+        Ok(Ok(item)) => {
+            let (artists, album_name) = match &item.unique_fields {
+                UniqueFields::Track { artists, album, .. } => (
+                    artists.iter().map(|artist| cstring_from_str_lossy(&artist.name)).collect(),
+                    Some(cstring_from_str_lossy(album)),
+                ),
+                UniqueFields::Local { artists, album, .. } => (
+                    artists
+                        .clone()
+                        .map(|artists| vec![cstring_from_str_lossy(&artists)])
+                        .unwrap_or_default(),
+                    album.clone().map(|album| cstring_from_str_lossy(&album)),
+                ),
+                UniqueFields::Episode { show_name, .. } => {
+                    (Vec::new(), Some(cstring_from_str_lossy(show_name)))
+                }
+            };
+            let cover_url = item
+                .covers
+                .first()
+                .map(|cover| cstring_from_str_lossy(&cover.url));
+            let handle = TrackHandle {
+                name: cstring_from_str_lossy(&item.name),
+                duration_ms: item.duration_ms,
+                artists,
+                album_name,
+                cover_url,
+            };
+            Box::into_raw(Box::new(handle)) as *mut cspot_track_t
+        }
+        Ok(Err(err)) => {
+            write_error(out_error, err.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            write_error(out_error, "panic while fetching track metadata");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns the track name.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_name(track: *const cspot_track_t) -> *const c_char {
+    if track.is_null() {
+        return ptr::null();
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(track as *const TrackHandle) };
+    handle.name.as_ptr()
+}
+
+/// Returns the track duration in milliseconds.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_duration_ms(track: *const cspot_track_t) -> u32 {
+    if track.is_null() {
+        return 0;
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(track as *const TrackHandle) };
+    handle.duration_ms
+}
+
+/// Returns the number of artists credited on the track.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_artist_count(track: *const cspot_track_t) -> usize {
+    if track.is_null() {
+        return 0;
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(track as *const TrackHandle) };
+    handle.artists.len()
+}
+
+/// Returns the name of the artist at `index`, or null if out of range.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_artist_name(
+    track: *const cspot_track_t,
+    index: usize,
+) -> *const c_char {
+    if track.is_null() {
+        return ptr::null();
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(track as *const TrackHandle) };
+    handle.artists.get(index).map_or(ptr::null(), |value| value.as_ptr())
+}
+
+/// Returns the album or show name, if available.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_album_name(track: *const cspot_track_t) -> *const c_char {
+    if track.is_null() {
+        return ptr::null();
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(track as *const TrackHandle) };
+    handle.album_name.as_ref().map_or(ptr::null(), |value| value.as_ptr())
+}
+
+/// Returns the largest available cover artwork URL, if available.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_cover_url(track: *const cspot_track_t) -> *const c_char {
+    if track.is_null() {
+        return ptr::null();
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(track as *const TrackHandle) };
+    handle.cover_url.as_ref().map_or(ptr::null(), |value| value.as_ptr())
+}
+
+/// Frees a track metadata handle.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_track_free(track: *mut cspot_track_t) {
+    if track.is_null() {
+        return;
+    }
+    // Safety: track must be a valid handle allocated by cspot.
+    unsafe {
+        drop(Box::from_raw(track as *mut TrackHandle));
+    }
+}
+
+/// Opaque album metadata handle for C callers.
+#[allow(non_camel_case_types)]
+pub struct cspot_album_t;
+
+struct AlbumHandle {
+    name: CString,
+    cover_url: Option<CString>,
+}
+
+/// Fetches metadata for an album.
+///
+/// `uri` accepts a `spotify:album:...` URI or a base62 id. Runs on `runtime()`.
+/// The returned handle must be released with `cspot_album_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_metadata_album_fetch(
+    session: *const cspot_session_t,
+    uri: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_album_t {
+    clear_error(out_error);
+    let session = match session_from_handle(session) {
+        Some(value) => value,
+        None => {
+            write_error(out_error, "session handle was null");
+            return ptr::null_mut();
+        }
+    };
+    let uri = match read_cstr(uri, "uri", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let id = match parse_spotify_id(&uri) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        runtime().block_on(async { Album::get(&session, &id).await })
+    }));
+
+    match result {
+        Ok(Ok(album)) => {
+            let handle = AlbumHandle {
+                name: cstring_from_str_lossy(&album.name),
+                cover_url: album.covers.first().map(|cover| cstring_from_str_lossy(&cover.url)),
+            };
+            Box::into_raw(Box::new(handle)) as *mut cspot_album_t
+        }
+        Ok(Err(err)) => {
+            write_error(out_error, err.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            write_error(out_error, "panic while fetching album metadata");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns the album name.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_album_name(album: *const cspot_album_t) -> *const c_char {
+    if album.is_null() {
+        return ptr::null();
+    }
+    // Safety: album must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(album as *const AlbumHandle) };
+    handle.name.as_ptr()
+}
+
+/// Returns the largest available cover artwork URL, if available.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_album_cover_url(album: *const cspot_album_t) -> *const c_char {
+    if album.is_null() {
+        return ptr::null();
+    }
+    // Safety: album must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(album as *const AlbumHandle) };
+    handle.cover_url.as_ref().map_or(ptr::null(), |value| value.as_ptr())
+}
+
+/// Frees an album metadata handle.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_album_free(album: *mut cspot_album_t) {
+    if album.is_null() {
+        return;
+    }
+    // Safety: album must be a valid handle allocated by cspot.
+    unsafe {
+        drop(Box::from_raw(album as *mut AlbumHandle));
+    }
+}
+
+/// Opaque artist metadata handle for C callers.
+#[allow(non_camel_case_types)]
+pub struct cspot_artist_t;
+
+struct ArtistHandle {
+    name: CString,
+}
+
+/// Fetches metadata for an artist.
+///
+/// `uri` accepts a `spotify:artist:...` URI or a base62 id. Runs on `runtime()`.
+/// The returned handle must be released with `cspot_artist_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_metadata_artist_fetch(
+    session: *const cspot_session_t,
+    uri: *const c_char,
+    out_error: *mut *mut cspot_error_t,
+) -> *mut cspot_artist_t {
+    clear_error(out_error);
+    let session = match session_from_handle(session) {
+        Some(value) => value,
+        None => {
+            write_error(out_error, "session handle was null");
+            return ptr::null_mut();
+        }
+    };
+    let uri = match read_cstr(uri, "uri", out_error) {
+        Some(value) => value,
+        None => return ptr::null_mut(),
+    };
+    let id = match parse_spotify_id(&uri) {
+        Ok(value) => value,
+        Err(err) => {
+            write_error(out_error, err);
+            return ptr::null_mut();
+        }
+    };
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        runtime().block_on(async { Artist::get(&session, &id).await })
+    }));
+
+    match result {
+        Ok(Ok(artist)) => {
+            let handle = ArtistHandle {
+                name: cstring_from_str_lossy(&artist.name),
+            };
+            Box::into_raw(Box::new(handle)) as *mut cspot_artist_t
+        }
+        Ok(Err(err)) => {
+            write_error(out_error, err.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            write_error(out_error, "panic while fetching artist metadata");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns the artist name.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_artist_name(artist: *const cspot_artist_t) -> *const c_char {
+    if artist.is_null() {
+        return ptr::null();
+    }
+    // Safety: artist must be a valid handle allocated by cspot.
+    let handle = unsafe { &*(artist as *const ArtistHandle) };
+    handle.name.as_ptr()
+}
+
+/// Frees an artist metadata handle.
+#[unsafe(no_mangle)]
+pub extern "C" fn cspot_artist_free(artist: *mut cspot_artist_t) {
+    if artist.is_null() {
+        return;
+    }
+    // Safety: artist must be a valid handle allocated by cspot.
+    unsafe {
+        drop(Box::from_raw(artist as *mut ArtistHandle));
+    }
+}